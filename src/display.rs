@@ -0,0 +1,15 @@
+use core::fmt;
+
+// Write labeled fields joined by commas, e.g. `A=13, B=Bob`:
+pub(crate) fn write_fields(
+    f: &mut fmt::Formatter<'_>,
+    fields: &[(&str, &dyn fmt::Display)],
+) -> fmt::Result {
+    for (i, (label, value)) in fields.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}={}", label, value)?;
+    }
+    Ok(())
+}
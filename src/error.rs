@@ -0,0 +1,27 @@
+use core::fmt;
+
+/// The error when converting from `Option` values which are all `None`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AllAbsent;
+
+impl fmt::Display for AllAbsent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no value of any accepted type present")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AllAbsent {}
+
+/// The error when merging "somevals" which both have the same component present
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConflictError;
+
+impl fmt::Display for ConflictError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the same component is present in both values")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConflictError {}
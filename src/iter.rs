@@ -0,0 +1,45 @@
+use core::array;
+
+/// An iterator over the present components of a "someval" whose type parameters are all `T`
+///
+/// Components are yielded in parameter order, or in reverse from the back.
+#[derive(Clone, Debug)]
+pub struct PresentIter<T, const N: usize> {
+    components: array::IntoIter<Option<T>, N>,
+    // The number of `Some` values left in `components`:
+    remaining: usize,
+}
+
+impl<T, const N: usize> PresentIter<T, N> {
+    pub(crate) fn new(components: [Option<T>; N]) -> Self {
+        let remaining = components.iter().filter(|c| c.is_some()).count();
+        PresentIter {
+            components: components.into_iter(),
+            remaining,
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for PresentIter<T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let next = self.components.by_ref().flatten().next();
+        self.remaining -= usize::from(next.is_some());
+        next
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for PresentIter<T, N> {
+    fn next_back(&mut self) -> Option<T> {
+        let next = self.components.by_ref().rev().flatten().next();
+        self.remaining -= usize::from(next.is_some());
+        next
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for PresentIter<T, N> {}
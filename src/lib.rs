@@ -46,7 +46,7 @@
 //! The "someval" types in this crate follow common structure, varying in the number of generics
 //! they support. They are all named `SomeN` with the suffix `N` indicating how many distinct values
 //! are possible, e.g. [Some2], [Some3], etc… So [Some2] is generic over two types: `Some2<A, B>`,
-//! while [Some3] is generic over three: `Some3<A, B, C>`, etc…
+//! while [Some3] is generic over three: `Some3<A, B, C>`, etc… up to [Some8].
 //!
 //! Each "someval" type is an enum. Both type parameters and enum variants use the uppercase
 //! English alphabet as placeholders, e.g.:
@@ -69,6 +69,126 @@
 //! let val = Triple::AC(42, false);
 //! ```
 //!
+//! Variant names list the present components in alphabetical order:
+//!
+//! ```
+//! use someval::Some4;
+//!
+//! type Quad = Some4<u8, u16, u32, u64>;
+//!
+//! let val = Quad::ABD(1, 2, 4);
+//! assert_eq!(val.d(), Some(4));
+//! ```
+//!
+//! Each "someval" type derives the standard traits `Copy`, `Clone`, `Debug`, `PartialEq`, `Eq`,
+//! `PartialOrd`, `Ord`, and `Hash` when the type parameters implement them, so they can be used,
+//! e.g., as `HashMap` keys:
+//!
+//! ```
+//! use std::collections::HashSet;
+//! use someval::Some2;
+//!
+//! type NameId = Some2<u64, &'static str>;
+//!
+//! let ids: HashSet<NameId> = [
+//!     NameId::A(42),
+//!     NameId::B("Alice"),
+//!     NameId::AB(42, "Alice"),
+//!     NameId::A(42),
+//! ]
+//! .into_iter()
+//! .collect();
+//!
+//! assert_eq!(ids.len(), 3);
+//! assert!(ids.contains(&NameId::AB(42, "Alice")));
+//! ```
+//!
+//! The derived ordering compares variants first, in declaration order, and only compares payloads
+//! within the same variant. Variants are declared with fewer components first, then in
+//! alphabetical order, e.g. `A < B < C < AB < AC < BC < ABC` for [Some3]. So the ordering is _not_
+//! based solely on the `A` payload:
+//!
+//! ```
+//! # use someval::Some2;
+//! type NameId = Some2<u64, &'static str>;
+//!
+//! let mut ids = vec![
+//!     NameId::AB(1, "Alice"),
+//!     NameId::B("Bob"),
+//!     NameId::A(42),
+//!     NameId::B("Alice"),
+//!     NameId::A(7),
+//! ];
+//! ids.sort();
+//!
+//! assert_eq!(
+//!     ids,
+//!     vec![
+//!         NameId::A(7),
+//!         NameId::A(42),
+//!         NameId::B("Alice"),
+//!         NameId::B("Bob"),
+//!         NameId::AB(1, "Alice"),
+//!     ],
+//! );
+//! ```
+//!
+//! When all type parameters implement `Display`, so does the "someval", rendering only the
+//! present components with their uppercase labels:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//! type NameId = Some2<u64, &'static str>;
+//!
+//! assert_eq!(NameId::A(42).to_string(), "A=42");
+//! assert_eq!(NameId::B("Bob").to_string(), "B=Bob");
+//! assert_eq!(NameId::AB(13, "Bob").to_string(), "A=13, B=Bob");
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # assert_eq!(Triple::A(1).to_string(), "A=1");
+//! # assert_eq!(Triple::B("x").to_string(), "B=x");
+//! # assert_eq!(Triple::C(true).to_string(), "C=true");
+//! # assert_eq!(Triple::AB(1, "x").to_string(), "A=1, B=x");
+//! # assert_eq!(Triple::AC(1, true).to_string(), "A=1, C=true");
+//! # assert_eq!(Triple::BC("x", true).to_string(), "B=x, C=true");
+//! # assert_eq!(Triple::ABC(1, "x", true).to_string(), "A=1, B=x, C=true");
+//! ```
+//!
+//! To format the present components some other way, `for_each_display` calls a closure with each
+//! one as a `&dyn Display`, in parameter order:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! let mut shown = vec![];
+//! Triple::AC(42, true).for_each_display(|x| shown.push(x.to_string()));
+//! assert_eq!(shown, vec!["42", "true"]);
+//! # let mut shown = vec![];
+//! # Triple::ABC(1, "x", false).for_each_display(|x| shown.push(x.to_string()));
+//! # assert_eq!(shown, vec!["1", "x", "false"]);
+//! # let mut shown = vec![];
+//! # Some2::<u8, &str>::B("y").for_each_display(|x| shown.push(x.to_string()));
+//! # assert_eq!(shown, vec!["y"]);
+//! ```
+//!
+//! For structured logging, `to_label_map` (with the `std` feature) renders the present components
+//! into a `BTreeMap` keyed by their lowercase labels:
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! # use someval::{Some2, Some3};
+//! use std::collections::BTreeMap;
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! let expected = BTreeMap::from([("a", "1".to_string()), ("c", "true".to_string())]);
+//! assert_eq!(Triple::AC(1, true).to_label_map(), expected);
+//! # let expected = BTreeMap::from([("b", "x".to_string())]);
+//! # assert_eq!(Some2::<u8, &str>::B("x").to_label_map(), expected);
+//! # }
+//! ```
+//!
 //! ## Constructing "somevals"
 //!
 //! Values can be constructed with the enum variants:
@@ -83,6 +203,94 @@
 //! let nid3 = NameId::AB(13, "Bob".to_string());
 //! ```
 //!
+//! Single component values can also be constructed with `new_a`, `new_b`, etc… These are
+//! `const fn`, so they work in `const` contexts, as do the enum variants themselves:
+//!
+//! ```
+//! use someval::{Some2, Some3};
+//!
+//! const DEFAULT: Some2<u32, bool> = Some2::new_a(5);
+//! assert_eq!(DEFAULT, Some2::A(5));
+//!
+//! const FLAG: Some3<u32, &str, bool> = Some3::new_c(true);
+//! assert_eq!(FLAG, Some3::C(true));
+//!
+//! const BOTH: Some2<u32, bool> = Some2::AB(5, true);
+//! assert_eq!(BOTH.count(), 2);
+//! ```
+//!
+//! The `from_a`, `from_b`, etc… constructors are equivalent, and read well without naming the
+//! variant letter. A "someval" does not implement `From` for a single component value, because
+//! that would be ambiguous when type parameters are the same type, e.g. `Some2<u64, u64>`:
+//!
+//! ```
+//! use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! assert_eq!(NameId::from_a(42), NameId::A(42));
+//! assert_eq!(NameId::from_b("Bob".to_string()), NameId::B("Bob".to_string()));
+//!
+//! type Pair = Some2<u64, u64>;
+//! assert_ne!(Pair::from_a(1), Pair::from_b(1));
+//! ```
+//!
+//! When the first type parameter implements [Default], the "someval" does too. The default always
+//! populates the `A` component and leaves the others absent, since the empty state is illegal:
+//!
+//! ```
+//! use someval::{Some2, Some3};
+//!
+//! assert_eq!(Some2::<u32, String>::default(), Some2::A(0));
+//! assert_eq!(Some3::<String, u8, bool>::default(), Some3::A(String::new()));
+//! ```
+//!
+//! Starting from any value, `with_a`, `with_b`, etc… add a component and return the upgraded
+//! value, for fluent construction. A component which is already present is overwritten:
+//!
+//! ```
+//! use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::A(42).with_b("Alice".to_string());
+//! assert_eq!(nid, NameId::AB(42, "Alice".to_string()));
+//! assert_eq!(nid.with_a(7), NameId::AB(7, "Alice".to_string()));
+//! # assert_eq!(NameId::B("x".to_string()).with_a(1), NameId::AB(1, "x".to_string()));
+//! # assert_eq!(NameId::A(1).with_a(2), NameId::A(2));
+//!
+//! let triple = Some3::<i64, &str, bool>::new_c(true).with_a(1).with_b("x");
+//! assert_eq!(triple, Some3::ABC(1, "x", true));
+//! # assert_eq!(Some3::<i64, &str, bool>::B("x").with_c(false), Some3::BC("x", false));
+//! ```
+//!
+//! Unlike `with_a` and `replace_a`, which overwrite, `ensure_a`, `ensure_b`, etc… only add a
+//! component if it is absent, and leave an existing one untouched (similar to `Entry::or_insert`
+//! for maps):
+//!
+//! ```
+//! use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::B("Alice".to_string()).ensure_a(42);
+//! assert_eq!(nid, NameId::AB(42, "Alice".to_string()));
+//! assert_eq!(nid.ensure_a(7), NameId::AB(42, "Alice".to_string()));
+//! # assert_eq!(NameId::A(1).ensure_a(2), NameId::A(1));
+//! # assert_eq!(NameId::A(1).ensure_b("x".to_string()), NameId::AB(1, "x".to_string()));
+//! # assert_eq!(Some3::<i64, &str, bool>::C(true).ensure_c(false), Some3::C(true));
+//! # assert_eq!(Some3::<i64, &str, bool>::B("x").ensure_c(false), Some3::BC("x", false));
+//! ```
+//!
+//! The [some] macro selects the variant from named components:
+//!
+//! ```
+//! use someval::{some, Some3};
+//!
+//! let val: Some3<i64, &str, bool> = some![a = 42, c = false];
+//! assert_eq!(val, Some3::AC(42, false));
+//! ```
+//!
 //! A value with all types present can be converted from a tuple of values via `From`:
 //!
 //! ```
@@ -93,15 +301,101 @@
 //! assert_eq!(nid3, nid4);
 //! ```
 //!
-//! A value can by fallibly converted from a tuple of `Option` values via `TryFrom`:
+//! The primary fallible constructor is `from_parts`, which takes a tuple of `Option` values, one
+//! per type parameter, and fails only if they are all `None`. It has the same shape for every
+//! arity, and `TryFrom` for the tuple is equivalent. For separate arguments, `try_from_options`
+//! returns an `Option` instead:
 //!
 //! ```
-//! # use someval::Some2;
+//! # use someval::{Some2, Some3};
 //! # type NameId = Some2<u64, String>;
-//! let res1 = NameId::try_from((Some(42), None));
-//! assert!(res1.is_ok());
+//! let res1 = NameId::from_parts((Some(42), None));
+//! assert_eq!(res1, Ok(NameId::A(42)));
 //! let res2 = NameId::try_from((None, None));
 //! assert!(res2.is_err());
+//! let opt = NameId::try_from_options(None, Some("Bob".to_string()));
+//! assert_eq!(opt, Some(NameId::B("Bob".to_string())));
+//! # assert_eq!(NameId::from_parts((None, None)), Err(someval::AllAbsent));
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # assert_eq!(Triple::from_parts((Some(1), None, Some(true))), Ok(Triple::AC(1, true)));
+//! # assert_eq!(Triple::from_parts((None, None, None)), Err(someval::AllAbsent));
+//! # assert_eq!(Triple::try_from((None, Some("x"), None)), Ok(Triple::B("x")));
+//! ```
+//!
+//! The free functions [combine2] and [combine3] are equivalent to `try_from_options` for [Some2]
+//! and [Some3]. Like the types, they are suffixed with the number of options they take, since a
+//! single `combine` function cannot accept a varying number of arguments:
+//!
+//! ```
+//! use someval::{combine2, combine3, Some2, Some3};
+//!
+//! assert_eq!(combine2(Some(42), None::<String>), Some(Some2::A(42)));
+//! assert_eq!(combine3(None::<i64>, Some("x"), Some(true)), Some(Some3::BC("x", true)));
+//! assert_eq!(combine3(None::<i64>, None::<&str>, None::<bool>), None);
+//! # assert_eq!(combine2(None::<u8>, None::<u8>), None);
+//! # assert_eq!(combine2(Some(1), Some(2)), Some(Some2::AB(1, 2)));
+//! ```
+//!
+//! To always succeed, `from_options_or` calls a fallback closure to produce the value only when
+//! every option is `None`:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//! # type NameId = Some2<u64, String>;
+//! let anonymous = || NameId::B("anonymous".to_string());
+//! assert_eq!(NameId::from_options_or(Some(42), None, anonymous), NameId::A(42));
+//! assert_eq!(NameId::from_options_or(None, None, anonymous), anonymous());
+//! # assert_eq!(NameId::from_options_or(None, Some("x".to_string()), || unreachable!()),
+//! #     NameId::B("x".to_string()));
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # assert_eq!(Triple::from_options_or(None, None, None, || Triple::C(true)), Triple::C(true));
+//! # assert_eq!(Triple::from_options_or(Some(1), None, Some(false), || unreachable!()),
+//! #     Triple::AC(1, false));
+//! ```
+//!
+//! When each input may also have failed, `try_from_results` takes a `Result<Option<_>, E>` per
+//! component. The first `Err` in parameter order is returned; otherwise the options are combined
+//! as with `try_from_options`, so `Ok(None)` means every component is absent:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//! # type NameId = Some2<u64, String>;
+//! let id: Result<Option<u64>, &str> = Ok(Some(42));
+//! let name: Result<Option<String>, &str> = Ok(None);
+//! assert_eq!(NameId::try_from_results(id, name), Ok(Some(NameId::A(42))));
+//!
+//! assert_eq!(NameId::try_from_results(Ok(None), Ok(None::<String>)), Ok::<_, ()>(None));
+//! assert_eq!(NameId::try_from_results(Ok(Some(42)), Err("no name")), Err("no name"));
+//! # assert_eq!(NameId::try_from_results(Err("no id"), Err("no name")), Err("no id"));
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # assert_eq!(Triple::try_from_results(Ok(Some(1)), Ok(None), Ok::<_, ()>(Some(false))),
+//! #     Ok(Some(Triple::AC(1, false))));
+//! # assert_eq!(Triple::try_from_results(Ok(None), Ok(Some("x")), Err(3)), Err(3));
+//! ```
+//!
+//! The error for the all-absent case is [AllAbsent], which implements [core::error::Error] so it
+//! composes with application error types:
+//!
+//! ```
+//! # use someval::{AllAbsent, Some2};
+//! # type NameId = Some2<u64, String>;
+//! #[derive(Debug)]
+//! enum ConfigError {
+//!     MissingNameId(AllAbsent),
+//! }
+//!
+//! impl From<AllAbsent> for ConfigError {
+//!     fn from(e: AllAbsent) -> Self {
+//!         ConfigError::MissingNameId(e)
+//!     }
+//! }
+//!
+//! fn parse(id: Option<u64>, name: Option<String>) -> Result<NameId, ConfigError> {
+//!     Ok(NameId::try_from((id, name))?)
+//! }
+//!
+//! assert!(matches!(parse(None, None), Err(ConfigError::MissingNameId(AllAbsent))));
+//! assert_eq!(AllAbsent.to_string(), "no value of any accepted type present");
 //! ```
 //!
 //! A "someval" like [Some2] can always be converted to a tuple of `Option` values:
@@ -117,6 +411,140 @@
 //! assert_eq!(optname, None);
 //! ```
 //!
+//! Since this works for any type parameters, a "someval" of references from `as_ref` converts to a
+//! tuple of `Option` references without consuming the original:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::B("Alice".to_string());
+//! let (optid, optname): (Option<&u64>, Option<&String>) = nid.as_ref().into();
+//! assert_eq!(optid, None);
+//! assert_eq!(optname, Some(&"Alice".to_string()));
+//! # let triple = Some3::<i64, &str, bool>::AC(1, true);
+//! # let refs: (Option<&i64>, Option<&&str>, Option<&bool>) = triple.as_ref().into();
+//! # assert_eq!(refs, (Some(&1), None, Some(&true)));
+//! ```
+//!
+//! The `as_option_refs` method is shorthand for this:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! let triple = Triple::AC(1, true);
+//! assert_eq!(triple.as_option_refs(), (Some(&1), None, Some(&true)));
+//! # assert_eq!(Some2::<u8, u8>::B(2).as_option_refs(), (None, Some(&2)));
+//! ```
+//!
+//! A "someval" can also be compared directly with that tuple, using the same mapping of present
+//! components to `Some`:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! assert_eq!(NameId::A(42), (Some(42), None));
+//! assert_ne!(NameId::A(42), (Some(42), Some("Alice".to_string())));
+//! assert_ne!(NameId::A(42), (None, None));
+//! # assert_eq!(NameId::AB(1, "x".to_string()), (Some(1), Some("x".to_string())));
+//! # assert_ne!(NameId::B("x".to_string()), (None, Some("y".to_string())));
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # assert_eq!(Triple::AC(1, true), (Some(1), None, Some(true)));
+//! # assert_ne!(Triple::AC(1, true), (Some(1), Some("x"), Some(true)));
+//! # assert_ne!(Triple::C(true), (None, None, Some(false)));
+//! ```
+//!
+//! When every component is expected, `all` gives them as a tuple only for the fully populated
+//! variant, and `None` otherwise:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! assert_eq!(NameId::AB(13, "Bob".to_string()).all(), Some((13, "Bob".to_string())));
+//! assert_eq!(NameId::A(42).all(), None);
+//! # assert_eq!(NameId::B("Bob".to_string()).all(), None);
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # assert_eq!(Triple::ABC(1, "x", true).all(), Some((1, "x", true)));
+//! # assert_eq!(Triple::AB(1, "x").all(), None);
+//! # assert_eq!(Triple::BC("x", true).all(), None);
+//! ```
+//!
+//! When every component is required, `expect_all` returns them as a tuple, and panics with the
+//! given message otherwise (similar to [Option::expect]):
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(13, "Bob".to_string());
+//! assert_eq!(nid.expect_all("id and name are required"), (13, "Bob".to_string()));
+//! # let triple = Some3::<i64, &str, bool>::ABC(1, "x", true);
+//! # assert_eq!(triple.expect_all("all required"), (1, "x", true));
+//! ```
+//!
+//! ```should_panic
+//! # use someval::Some3;
+//! // Panics with "all required":
+//! Some3::<i64, &str, bool>::AC(1, true).expect_all("all required");
+//! ```
+//!
+//! A [Some2] can be converted to a `Result` with `into_result`, treating `A` as `Ok` and `B` as
+//! `Err`. This is lossy for [Some2::AB], which prefers `A` and drops the `B` component:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type Outcome = Some2<u64, &'static str>;
+//!
+//! assert_eq!(Outcome::A(42).into_result(), Ok(42));
+//! assert_eq!(Outcome::B("failed").into_result(), Err("failed"));
+//! assert_eq!(Outcome::AB(42, "failed").into_result(), Ok(42));
+//! ```
+//!
+//! The same conversion is available as `From<Some2<A, B>>` for `Result<A, B>`, with the same
+//! preference for `A` in [Some2::AB], for generic conversion code:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type Outcome = Some2<u64, &'static str>;
+//!
+//! fn check(outcome: Outcome) -> Result<u64, &'static str> {
+//!     let id = Result::from(outcome)?;
+//!     Ok(id + 1)
+//! }
+//!
+//! assert_eq!(check(Outcome::A(42)), Ok(43));
+//! assert_eq!(check(Outcome::B("failed")), Err("failed"));
+//! assert_eq!(check(Outcome::AB(42, "failed")), Ok(43));
+//! # let res: Result<u64, &str> = Outcome::B("x").into();
+//! # assert_eq!(res, Err("x"));
+//! ```
+//!
+//! The `B` component can be converted into the error type at the same time with
+//! `into_result_map_err` (similar to [Result::map_err]):
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type Outcome = Some2<u64, &'static str>;
+//!
+//! let to_error = |msg: &str| format!("error: {}", msg);
+//! let res = Outcome::B("failed").into_result_map_err(to_error);
+//! assert_eq!(res, Err("error: failed".to_string()));
+//! assert_eq!(Outcome::A(42).into_result_map_err(to_error), Ok(42));
+//! # let res = Outcome::AB(42, "failed").into_result_map_err(|_| unreachable!());
+//! # assert_eq!(res, Ok::<_, ()>(42));
+//! ```
+//!
 //! Most methods on a "someval" use a copy/move receiver and each provides an `as_ref` method to
 //! convert to references (similar to [Option::as_ref] and [Result::as_ref], not to be confused
 //! with [AsRef::as_ref]):
@@ -134,8 +562,124 @@
 //! assert_eq!(*idref, 42);
 //! ```
 //!
+//! Because references are always [Copy], so is the result of `as_ref`, even when the components
+//! themselves are not. It can be passed around freely without moving out of it:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! let value = Some2::<String, Vec<u8>>::AB("Alice".to_string(), vec![1, 2]);
+//! let r = value.as_ref();
+//! let r2 = r;
+//! assert_eq!(r, r2);
+//! assert_eq!(r.a(), Some(&"Alice".to_string()));
+//! # let triple = Some3::<String, Vec<u8>, Box<u64>>::C(Box::new(7));
+//! # let r = triple.as_ref();
+//! # let (r2, r3) = (r, r);
+//! # assert_eq!(r2, r3);
+//! ```
+//!
+//! When every type parameter implements [Deref](core::ops::Deref), `as_deref` borrows each
+//! component as its deref target (similar to [Option::as_deref]). The result borrows from the
+//! original "someval", so e.g. a `Some2<String, Vec<u8>>` can be passed as `Some2<&str, &[u8]>`:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! let owned = Some2::<String, Vec<u8>>::AB("Alice".to_string(), vec![1, 2]);
+//! let borrowed: Some2<&str, &[u8]> = owned.as_deref();
+//! assert_eq!(borrowed, Some2::AB("Alice", &[1, 2][..]));
+//! # let owned = Some2::<String, Vec<u8>>::B(vec![]);
+//! # assert_eq!(owned.as_deref(), Some2::B(&[][..]));
+//! # let owned = Some2::<String, Vec<u8>>::A("Bob".to_string());
+//! # assert_eq!(owned.as_deref(), Some2::A("Bob"));
+//! ```
+//!
+//! This works for every arity and through smart pointers such as `Box`:
+//!
+//! ```
+//! # use someval::Some3;
+//!
+//! let boxed = Some3::<Box<u64>, String, Vec<u8>>::AC(Box::new(42), vec![1]);
+//! let borrowed: Some3<&u64, &str, &[u8]> = boxed.as_deref();
+//! assert_eq!(borrowed, Some3::AC(&42, &[1][..]));
+//! # let boxed = Some3::<Box<u64>, String, Vec<u8>>::B("x".to_string());
+//! # assert_eq!(boxed.as_deref(), Some3::B("x"));
+//! ```
+//!
+//! A "someval" of references can be converted back to owned values with `cloned`, or `copied`
+//! when every component is [Copy] (similar to [Option::cloned] and [Option::copied]):
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(42, "Alice".to_string());
+//! assert_eq!(nid.as_ref().cloned(), nid);
+//!
+//! let triple = Some3::<i64, char, bool>::AC(1, true);
+//! assert_eq!(triple.as_ref().copied(), triple);
+//! # assert_eq!(NameId::B("x".to_string()).as_ref().cloned(), NameId::B("x".to_string()));
+//! # assert_eq!(Some3::<i64, char, bool>::B('x').as_ref().copied(), Some3::B('x'));
+//! ```
+//!
+//! More generally, `into_owned` (with the `std` feature) converts each borrowed component via
+//! `ToOwned` (similar to `Cow::into_owned`), which also covers unsized references such as those
+//! produced by `as_deref`:
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! # use someval::{Some2, Some3};
+//!
+//! let borrowed = Some2::<&str, &[u8]>::AB("Alice", &[1, 2]);
+//! let owned: Some2<String, Vec<u8>> = borrowed.into_owned();
+//! assert_eq!(owned, Some2::AB("Alice".to_string(), vec![1, 2]));
+//! # let owned: Some2<String, Vec<u8>> = Some2::<&str, &[u8]>::B(&[]).into_owned();
+//! # assert_eq!(owned, Some2::B(vec![]));
+//! # let owned = Some3::<String, Vec<u8>, u64>::AC("x".to_string(), 3);
+//! # assert_eq!(owned.as_ref().into_owned(), owned);
+//! # }
+//! ```
+//!
+//! Likewise `as_mut` converts to mutable references (similar to [Option::as_mut]), which allows
+//! mutating a value in place without rebuilding it:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let mut nid = NameId::AB(42, "Alice".to_string());
+//! if let Some2::AB(id, _) = nid.as_mut() {
+//!     *id += 1;
+//! }
+//! assert_eq!(nid, NameId::AB(43, "Alice".to_string()));
+//!
+//! let mut triple = Some3::<i64, &'static str, bool>::AC(42, false);
+//! if let Some3::AC(x, _) = triple.as_mut() {
+//!     *x = -1;
+//! }
+//! assert_eq!(triple, Some3::AC(-1, false));
+//! ```
+//!
+//! For a single component, `a_mut` (etc…) gives a mutable reference when it is present:
+//!
+//! ```
+//! # use someval::Some3;
+//!
+//! let mut triple = Some3::<i64, String, bool>::ABC(42, "x".to_string(), false);
+//! if let Some(b) = triple.b_mut() {
+//!     b.push('y');
+//! }
+//! assert_eq!(triple, Some3::ABC(42, "xy".to_string(), false));
+//! assert_eq!(Some3::<i64, String, bool>::A(42).b_mut(), None);
+//! ```
+//!
 //! Individual accessor methods give an `Option` for components (similar to [Result::ok] and
-//! [Result::err]):
+//! [Result::err]). These consume the "someval", while the `_ref` accessors like `a_ref` borrow
+//! it, as a shorthand for `as_ref().a()`:
 //!
 //! ```
 //! # use someval::Some2;
@@ -143,11 +687,1477 @@
 //! type NameId = Some2<u64, String>;
 //!
 //! let nid = NameId::A(42);
-//! assert_eq!(nid.as_ref().a(), Some(&42));
-//! assert_eq!(nid.as_ref().b(), None);
+//! assert_eq!(nid.a_ref(), Some(&42));
+//! assert_eq!(nid.b_ref(), None);
+//! assert_eq!(nid.a(), Some(42));
 //! ```
-mod some2;
-mod some3;
+//!
+//! By contrast, `only_a` (etc…) gives the component only when it is the _sole_ component present,
+//! i.e. only for the exact `A` variant, and `None` for combined variants like `AB`:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! assert_eq!(NameId::A(42).only_a(), Some(42));
+//! assert_eq!(NameId::AB(42, "Alice".to_string()).only_a(), None);
+//! assert_eq!(NameId::AB(42, "Alice".to_string()).a(), Some(42));
+//! # assert_eq!(NameId::B("Bob".to_string()).only_a(), None);
+//! # assert_eq!(NameId::B("Bob".to_string()).only_b(), Some("Bob".to_string()));
+//! # assert_eq!(someval::Some3::<u8, u8, u8>::C(3).only_c(), Some(3));
+//! # assert_eq!(someval::Some3::<u8, u8, u8>::AC(1, 3).only_c(), None);
+//! ```
+//!
+//! Presence of a component can be checked with predicates like `is_a` (similar to
+//! [Option::is_some]), which are true for every variant containing that component:
+//!
+//! ```
+//! # use someval::Some3;
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! let val = Triple::AC(42, false);
+//! assert!(val.is_a());
+//! assert!(!val.is_b());
+//! assert!(val.is_c());
+//! # assert!(Triple::A(1).is_a());
+//! # assert!(Triple::AB(1, "x").is_a());
+//! # assert!(Triple::ABC(1, "x", true).is_a());
+//! # assert!(!Triple::BC("x", true).is_a());
+//! # assert!(Triple::BC("x", true).is_b());
+//! # assert!(!Triple::C(true).is_b());
+//! ```
+//!
+//! The number of present components is given by `count`:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! assert_eq!(Triple::AC(42, false).count(), 2);
+//! # assert_eq!(Some2::<u8, u8>::A(1).count(), 1);
+//! # assert_eq!(Some2::<u8, u8>::B(1).count(), 1);
+//! # assert_eq!(Some2::<u8, u8>::AB(1, 2).count(), 2);
+//! # assert_eq!(Triple::A(1).count(), 1);
+//! # assert_eq!(Triple::B("x").count(), 1);
+//! # assert_eq!(Triple::C(true).count(), 1);
+//! # assert_eq!(Triple::AB(1, "x").count(), 2);
+//! # assert_eq!(Triple::BC("x", true).count(), 2);
+//! # assert_eq!(Triple::ABC(1, "x", true).count(), 3);
+//! ```
+//!
+//! The present components are also given as a bitset by `present_mask`, where bit 0 is set when
+//! `A` is present, bit 1 for `B`, bit 2 for `C`, and so on. This layout is stable, so masks are
+//! safe to store or transmit:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! assert_eq!(Triple::AC(42, false).present_mask(), 0b101);
+//! assert_eq!(Some2::<u8, u8>::AB(1, 2).present_mask(), 0b11);
+//! # assert_eq!(Some2::<u8, u8>::A(1).present_mask(), 0b01);
+//! # assert_eq!(Some2::<u8, u8>::B(1).present_mask(), 0b10);
+//! # assert_eq!(Triple::A(1).present_mask(), 0b001);
+//! # assert_eq!(Triple::B("x").present_mask(), 0b010);
+//! # assert_eq!(Triple::C(true).present_mask(), 0b100);
+//! # assert_eq!(Triple::AB(1, "x").present_mask(), 0b011);
+//! # assert_eq!(Triple::BC("x", true).present_mask(), 0b110);
+//! # assert_eq!(Triple::ABC(1, "x", true).present_mask(), 0b111);
+//! # assert_eq!(someval::Some8::<u8, u8, u8, u8, u8, u8, u8, u8>::H(1).present_mask(), 0x80);
+//! ```
+//!
+//! For code which branches on named components, `presence` gives a struct with a `bool` field per
+//! component, such as [Some3Presence] for [Some3]:
+//!
+//! ```
+//! # use someval::{Some2, Some2Presence, Some3, Some3Presence};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! let presence = Triple::AC(1, true).presence();
+//! assert_eq!(presence, Some3Presence { a: true, b: false, c: true });
+//! if !presence.b {
+//!     // Report the missing `B` component…
+//! }
+//! # assert_eq!(Triple::B("x").presence(), Some3Presence { a: false, b: true, c: false });
+//! # assert_eq!(Triple::ABC(1, "x", true).presence(), Some3Presence { a: true, b: true, c: true });
+//! # assert_eq!(Some2::<u8, u8>::A(1).presence(), Some2Presence { a: true, b: false });
+//! ```
+//!
+//! Both `present_mask` and `variant_name` are `const fn`, so they can be evaluated at compile time:
+//!
+//! ```
+//! # use someval::Some3;
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! const AC_MASK: u8 = Triple::AC(0, false).present_mask();
+//! const AC_NAME: &str = Triple::AC(0, false).variant_name();
+//! assert_eq!(AC_MASK, 0b101);
+//! assert_eq!(AC_NAME, "AC");
+//! ```
+//!
+//! For programmatic access, `contains` checks a component given as a [Slot]. A slot beyond the
+//! arity of the type is never present:
+//!
+//! ```
+//! # use someval::{Slot, Some2, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! let triple = Triple::AC(1, true);
+//! let present: Vec<Slot> = [Slot::A, Slot::B, Slot::C]
+//!     .into_iter()
+//!     .filter(|&slot| triple.contains(slot))
+//!     .collect();
+//! assert_eq!(present, vec![Slot::A, Slot::C]);
+//! assert!(!triple.contains(Slot::D));
+//! # let slots = [Slot::A, Slot::B, Slot::C];
+//! # for triple in [
+//! #     Triple::A(1), Triple::B("x"), Triple::C(true), Triple::AB(1, "x"), Triple::AC(1, true),
+//! #     Triple::BC("x", true), Triple::ABC(1, "x", true),
+//! # ] {
+//! #     let expected = [triple.is_a(), triple.is_b(), triple.is_c()];
+//! #     assert_eq!(slots.map(|slot| triple.contains(slot)), expected);
+//! # }
+//! # assert!(Some2::<u8, u8>::B(1).contains(Slot::B));
+//! # assert!(!Some2::<u8, u8>::B(1).contains(Slot::A));
+//! # assert!(!Some2::<u8, u8>::AB(1, 2).contains(Slot::H));
+//! # assert!(someval::Some8::<u8, u8, u8, u8, u8, u8, u8, u8>::H(1).contains(Slot::H));
+//! ```
+//!
+//! To analyze many [Some2] values, [partition_by_presence] counts how many there are of each
+//! variant:
+//!
+//! ```
+//! # use someval::{partition_by_presence, PartitionStats, Some2};
+//!
+//! type NameId = Some2<u64, &'static str>;
+//!
+//! let nids = vec![NameId::A(1), NameId::AB(2, "Bob"), NameId::A(3), NameId::B("Eve")];
+//! let stats = partition_by_presence(nids);
+//! assert_eq!(stats, PartitionStats { a: 2, b: 1, ab: 1 });
+//! assert_eq!(stats.a + stats.ab, 3); // Values with an `A` component
+//! # assert_eq!(partition_by_presence(Vec::<NameId>::new()), PartitionStats::default());
+//! ```
+//!
+//! For diagnostics, `components_present` gives the lowercase labels of the present components in
+//! parameter order:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! let labels: Vec<&str> = Triple::AC(1, true).components_present().collect();
+//! assert_eq!(labels, vec!["a", "c"]);
+//! assert_eq!(labels.join(", "), "a, c");
+//! # let labels: Vec<&str> = Triple::ABC(1, "x", true).components_present().collect();
+//! # assert_eq!(labels, vec!["a", "b", "c"]);
+//! # let labels: Vec<&str> = Some2::<u8, u8>::B(1).components_present().collect();
+//! # assert_eq!(labels, vec!["b"]);
+//! ```
+//!
+//! Unlike `==`, `shares_component` matches two values when any component is present in both with
+//! equal payloads, regardless of which other components are present:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let alice = NameId::AB(42, "Alice".to_string());
+//! assert!(alice.shares_component(&NameId::A(42)));
+//! assert!(alice.shares_component(&NameId::AB(7, "Alice".to_string())));
+//! assert!(!alice.shares_component(&NameId::A(7)));
+//! assert!(!NameId::A(42).shares_component(&NameId::B("Alice".to_string())));
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # assert!(Triple::BC("x", true).shares_component(&Triple::C(true)));
+//! # assert!(!Triple::BC("x", true).shares_component(&Triple::AC(1, false)));
+//! # assert!(Triple::ABC(1, "x", true).shares_component(&Triple::B("x")));
+//! ```
+//!
+//! To compare only which components are present, ignoring payloads, use `same_variant`. This
+//! doesn't require the payloads to implement `PartialEq`:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! struct Opaque;
+//!
+//! type Triple = Some3<i64, Opaque, bool>;
+//!
+//! assert!(Triple::AB(1, Opaque).same_variant(&Triple::AB(2, Opaque)));
+//! assert!(!Triple::AB(1, Opaque).same_variant(&Triple::AC(1, true)));
+//! # assert!(Some2::<u8, u8>::B(1).same_variant(&Some2::B(2)));
+//! # assert!(!Some2::<u8, u8>::B(1).same_variant(&Some2::AB(0, 1)));
+//! ```
+//!
+//! For logging, `variant_name` gives the variant identifier without any payloads (unlike
+//! `Debug`):
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! assert_eq!(Triple::AC(42, false).variant_name(), "AC");
+//! # assert_eq!(Some2::<u8, u8>::A(1).variant_name(), "A");
+//! # assert_eq!(Some2::<u8, u8>::B(1).variant_name(), "B");
+//! # assert_eq!(Some2::<u8, u8>::AB(1, 2).variant_name(), "AB");
+//! # assert_eq!(Triple::A(1).variant_name(), "A");
+//! # assert_eq!(Triple::B("x").variant_name(), "B");
+//! # assert_eq!(Triple::C(true).variant_name(), "C");
+//! # assert_eq!(Triple::AB(1, "x").variant_name(), "AB");
+//! # assert_eq!(Triple::BC("x", true).variant_name(), "BC");
+//! # assert_eq!(Triple::ABC(1, "x", true).variant_name(), "ABC");
+//! ```
+//!
+//! The [match_present] macro runs a block for each present component, without listing every
+//! variant which includes it:
+//!
+//! ```
+//! # use someval::{match_present, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! let mut ids = vec![];
+//! for triple in [Triple::A(1), Triple::BC("x", true), Triple::ABC(3, "y", false)] {
+//!     match_present!(triple, a(id) => { ids.push(*id); });
+//! }
+//! assert_eq!(ids, vec![1, 3]);
+//! ```
+//!
+//! To document an invariant inline, the [assert_present] macro checks that a component is
+//! present in debug builds, and compiles to nothing in release builds:
+//!
+//! ```should_panic
+//! # use someval::{assert_present, Some2};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(42, "Alice".to_string());
+//! assert_present!(nid, b);
+//!
+//! let nid = NameId::A(42);
+//! assert_present!(nid, b); // panics
+//! ```
+//!
+//! When a component is known to be present, `unwrap_a` and `expect_a` (etc…) return it directly,
+//! panicking otherwise (similar to [Option::unwrap] and [Option::expect]):
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(42, "Alice".to_string());
+//! assert_eq!(nid.clone().unwrap_a(), 42);
+//! assert_eq!(nid.expect_b("a name is required"), "Alice");
+//! ```
+//!
+//! The `unwrap_a` panic message names the missing component:
+//!
+//! ```should_panic
+//! # use someval::Some2;
+//! # type NameId = Some2<u64, String>;
+//! // Panics with "called `Some2::unwrap_b()` on a value with no `b` component":
+//! NameId::A(42).unwrap_b();
+//! ```
+//!
+//! Fallbacks for an absent component are given with `a_or` and `a_or_else` (etc…), similar to
+//! [Option::unwrap_or] and [Option::unwrap_or_else]. Like all of the single component accessors,
+//! these consume the "someval" and drop the other components:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! assert_eq!(NameId::A(42).b_or("anonymous".to_string()), "anonymous");
+//! assert_eq!(NameId::B("Bob".to_string()).a_or_else(|| 7), 7);
+//! assert_eq!(NameId::AB(42, "Alice".to_string()).a_or(7), 42);
+//! ```
+//!
+//! Similarly, `a_ok_or` and `a_ok_or_else` (etc…) convert to a `Result` keyed on one component
+//! (similar to [Option::ok_or] and [Option::ok_or_else]):
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! assert_eq!(NameId::A(42).a_ok_or("no id"), Ok(42));
+//! assert_eq!(NameId::B("Bob".to_string()).a_ok_or("no id"), Err("no id"));
+//! assert_eq!(
+//!     NameId::A(42).b_ok_or_else(|| format!("no name for {}", 42)),
+//!     Err("no name for 42".to_string()),
+//! );
+//! # assert_eq!(NameId::B("Bob".to_string()).b_ok_or_else(|| "no name"), Ok("Bob".to_string()));
+//! ```
+//!
+//! A summary of one component with a fallback is given by `a_map_or` and `a_map_or_else`
+//! (etc…), similar to [Option::map_or] and [Option::map_or_else]:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! assert_eq!(NameId::AB(42, "Alice".to_string()).b_map_or(0, |name| name.len()), 5);
+//! assert_eq!(NameId::A(42).b_map_or(0, |name| name.len()), 0);
+//! assert_eq!(NameId::A(42).a_map_or_else(|| "none".to_string(), |id| id.to_string()), "42");
+//! # let bob = NameId::B("Bob".to_string());
+//! # assert_eq!(bob.a_map_or_else(|| "none".to_string(), |id| id.to_string()), "none");
+//! ```
+//!
+//! To compute something from a borrowed component without consuming or cloning the "someval",
+//! use `a_map_ref`, `b_map_ref`, etc…, which give `None` if the component is absent:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(42, "Alice".to_string());
+//! assert_eq!(nid.b_map_ref(String::len), Some(5));
+//! assert_eq!(NameId::A(42).b_map_ref(String::len), None);
+//! assert_eq!(nid, NameId::AB(42, "Alice".to_string()));
+//! # assert_eq!(nid.a_map_ref(|id| id + 1), Some(43));
+//! # assert_eq!(Some3::<u8, u8, bool>::C(true).c_map_ref(|c| !c), Some(false));
+//! ```
+//!
+//! For iterator chains, `into_iter_a` (etc…) consumes the "someval" and yields its `A` component
+//! if present, using the standard [Option] iterator:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid1 = NameId::AB(42, "Alice".to_string());
+//! let nid2 = NameId::B("Bob".to_string());
+//! let nid3 = NameId::A(7);
+//!
+//! let ids: Vec<u64> = nid1
+//!     .into_iter_a()
+//!     .chain(nid2.into_iter_a())
+//!     .chain(nid3.into_iter_a())
+//!     .collect();
+//! assert_eq!(ids, vec![42, 7]);
+//! ```
+//!
+//! To observe a component without breaking a method chain, `inspect_a` (etc…) calls a closure
+//! with a reference to the component if it is present, then returns the "someval" unchanged,
+//! similar to [Option::inspect]:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let mut seen = vec![];
+//! let nid = NameId::AB(42, "Alice".to_string()).inspect_a(|&id| seen.push(id));
+//! assert_eq!(nid, NameId::AB(42, "Alice".to_string()));
+//!
+//! let nid = NameId::B("Bob".to_string()).inspect_a(|&id| seen.push(id));
+//! assert_eq!(nid, NameId::B("Bob".to_string()));
+//! assert_eq!(seen, vec![42]);
+//! # let mut names = vec![];
+//! # let _ = NameId::A(1).inspect_b(|name| names.push(name.clone()));
+//! # let _ = NameId::B("Bob".to_string()).inspect_b(|name| names.push(name.clone()));
+//! # assert_eq!(names, vec!["Bob".to_string()]);
+//! ```
+//!
+//! ## Modifying "somevals" in place
+//!
+//! A component can be inserted with `replace_a` (etc…), which returns the previous value of that
+//! component, if any (similar to [Option::replace]). Inserting an absent component changes the
+//! variant:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let mut nid = NameId::B("Alice".to_string());
+//! assert_eq!(nid.replace_a(42), None);
+//! assert_eq!(nid, NameId::AB(42, "Alice".to_string()));
+//!
+//! assert_eq!(nid.replace_a(7), Some(42));
+//! assert_eq!(nid, NameId::AB(7, "Alice".to_string()));
+//! # let mut nid = NameId::A(1);
+//! # assert_eq!(nid.replace_a(2), Some(1));
+//! # assert_eq!(nid, NameId::A(2));
+//! # assert_eq!(nid.replace_b("x".to_string()), None);
+//! # assert_eq!(nid, NameId::AB(2, "x".to_string()));
+//! # let mut nid = NameId::B("x".to_string());
+//! # assert_eq!(nid.replace_b("y".to_string()), Some("x".to_string()));
+//! # assert_eq!(nid, NameId::B("y".to_string()));
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # let cases = [
+//! #     (Triple::A(1), Triple::AB(1, "new"), None),
+//! #     (Triple::B("x"), Triple::B("new"), Some("x")),
+//! #     (Triple::C(true), Triple::BC("new", true), None),
+//! #     (Triple::AB(1, "x"), Triple::AB(1, "new"), Some("x")),
+//! #     (Triple::AC(1, true), Triple::ABC(1, "new", true), None),
+//! #     (Triple::BC("x", true), Triple::BC("new", true), Some("x")),
+//! #     (Triple::ABC(1, "x", true), Triple::ABC(1, "new", true), Some("x")),
+//! # ];
+//! # for (mut before, after, prev) in cases {
+//! #     assert_eq!(before.replace_b("new"), prev);
+//! #     assert_eq!(before, after);
+//! # }
+//! ```
+//!
+//! A component can be removed with `take_a` (etc…), similar to [Option::take], but only when
+//! another component remains present. If the component is absent, or is the only component
+//! present, `take_a` returns `None` and leaves the value unchanged, since removing it would leave
+//! no value present:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let mut nid = NameId::AB(42, "Alice".to_string());
+//! assert_eq!(nid.take_a(), Some(42));
+//! assert_eq!(nid, NameId::B("Alice".to_string()));
+//!
+//! // The sole component is not removed:
+//! assert_eq!(nid.take_b(), None);
+//! assert_eq!(nid, NameId::B("Alice".to_string()));
+//! # assert_eq!(nid.take_a(), None);
+//! # assert_eq!(nid, NameId::B("Alice".to_string()));
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # let cases = [
+//! #     (Triple::A(1), Triple::A(1), None),
+//! #     (Triple::B("x"), Triple::B("x"), None),
+//! #     (Triple::C(true), Triple::C(true), None),
+//! #     (Triple::AB(1, "x"), Triple::B("x"), Some(1)),
+//! #     (Triple::AC(1, true), Triple::C(true), Some(1)),
+//! #     (Triple::BC("x", true), Triple::BC("x", true), None),
+//! #     (Triple::ABC(1, "x", true), Triple::BC("x", true), Some(1)),
+//! # ];
+//! # for (mut before, after, taken) in cases {
+//! #     assert_eq!(before.take_a(), taken);
+//! #     assert_eq!(before, after);
+//! # }
+//! ```
+//!
+//! The whole value can be transformed in place with `replace_with`, which passes the current value
+//! to a closure and stores the result, e.g. for a state machine whose states are variants. The
+//! closure can change which components are present. If it panics, the process aborts instead of
+//! unwinding, since `self` would otherwise be left without a valid value:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let mut nid = NameId::A(42);
+//! nid.replace_with(|nid| match nid {
+//!     NameId::A(id) => NameId::B(format!("user{}", id)),
+//!     other => other,
+//! });
+//! assert_eq!(nid, NameId::B("user42".to_string()));
+//! # nid.replace_with(|nid| nid.with_a(7));
+//! # assert_eq!(nid, NameId::AB(7, "user42".to_string()));
+//! ```
+//!
+//! The whole value can be moved out with `take` (similar to [core::mem::take]), which leaves the
+//! [Default] behind. So it requires the first type parameter to implement [Default], and the
+//! placeholder has only the `A` component:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let mut nid = NameId::AB(42, "Alice".to_string());
+//! assert_eq!(nid.take(), NameId::AB(42, "Alice".to_string()));
+//! assert_eq!(nid, NameId::A(0));
+//! # let mut triple = Some3::<String, u8, bool>::C(true);
+//! # assert_eq!(triple.take(), Some3::C(true));
+//! # assert_eq!(triple, Some3::A(String::new()));
+//! ```
+//!
+//! To mutate a component which may be absent, `entry_a`, `entry_b`, etc… give a mutable reference
+//! to it, first inserting its [Default] if needed (similar to `Entry::or_default` for maps):
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Counts = Some2<u32, Vec<&'static str>>;
+//!
+//! let mut counts = Counts::B(vec!["x"]);
+//! *counts.entry_a() += 1;
+//! counts.entry_b().push("y");
+//! assert_eq!(counts, Counts::AB(1, vec!["x", "y"]));
+//!
+//! *counts.entry_a() += 1;
+//! assert_eq!(counts, Counts::AB(2, vec!["x", "y"]));
+//! # let mut triple = Some3::<u8, u8, bool>::A(1);
+//! # *triple.entry_c() = true;
+//! # assert_eq!(triple, Some3::AC(1, true));
+//! # *triple.entry_b() += 5;
+//! # assert_eq!(triple, Some3::ABC(1, 5, true));
+//! ```
+//!
+//! ## Filtering "somevals"
+//!
+//! A component can be dropped when it fails a predicate with `filter_a` (etc…), similar to
+//! [Option::filter]. The predicate is only called if the component is present. The result is
+//! `None` only when the rejected component was the only one present, since no value would remain:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(0, "Alice".to_string());
+//! assert_eq!(nid.filter_a(|&id| id > 0), Some(NameId::B("Alice".to_string())));
+//!
+//! let nid = NameId::AB(42, "Alice".to_string());
+//! assert_eq!(nid.clone().filter_a(|&id| id > 0), Some(nid));
+//!
+//! // Rejecting the only component leaves nothing:
+//! assert_eq!(NameId::A(0).filter_a(|&id| id > 0), None);
+//!
+//! // Absent components are unaffected:
+//! let nid = NameId::B("Bob".to_string());
+//! assert_eq!(nid.clone().filter_a(|_| false), Some(nid));
+//! ```
+//!
+//! Several components can be validated in one call with `retain_present`, which takes a predicate
+//! per type parameter and drops each present component whose predicate returns `false`. It never
+//! produces an empty value: if every present component would be dropped, the original is returned
+//! unchanged as the `Err` value:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(0, "Alice".to_string());
+//! let valid = nid.retain_present(|&id| id > 0, |name| !name.is_empty());
+//! assert_eq!(valid, Ok(NameId::B("Alice".to_string())));
+//!
+//! // Every predicate fails, so nothing is dropped:
+//! let nid = NameId::AB(0, String::new());
+//! let valid = nid.clone().retain_present(|&id| id > 0, |name| !name.is_empty());
+//! assert_eq!(valid, Err(nid));
+//! # assert_eq!(NameId::A(0).retain_present(|_| false, |_| unreachable!()), Err(NameId::A(0)));
+//! # assert_eq!(NameId::A(1).retain_present(|_| true, |_| unreachable!()), Ok(NameId::A(1)));
+//! # type Triple = Some3<i64, &'static str, bool>;
+//! # let triple = Triple::ABC(1, "x", true);
+//! # assert_eq!(triple.retain_present(|_| true, |_| false, |&c| c), Ok(Triple::AC(1, true)));
+//! # assert_eq!(triple.retain_present(|_| false, |_| false, |_| false), Err(triple));
+//! ```
+//!
+//! ## Transforming "somevals"
+//!
+//! A single component can be transformed with a per-component `map` method, such as `map_a`,
+//! leaving the other components untouched (similar to [Option::map]). If the component is absent,
+//! the closure is not called and the value is preserved:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(42, "Alice".to_string());
+//! assert_eq!(nid.map_a(|id| id * 2), Some2::AB(84, "Alice".to_string()));
+//!
+//! let nid = NameId::B("Bob".to_string());
+//! assert_eq!(nid.map_a(|id| id * 2), Some2::B("Bob".to_string()));
+//!
+//! let triple = Some3::<i64, &'static str, bool>::BC("x", true);
+//! assert_eq!(triple.map_c(|c| !c), Some3::BC("x", false));
+//! ```
+//!
+//! These methods return a new value rather than modifying `self`, so they are `#[must_use]`, as are
+//! the other methods without side effects. Discarding the result is a warning:
+//!
+//! ```compile_fail
+//! #![deny(unused_must_use)]
+//! # use someval::Some2;
+//!
+//! let nid = Some2::<u64, String>::A(42);
+//! nid.map_a(|id| id * 2);
+//! ```
+//!
+//! A fallible closure can be applied with `try_map_a`, `try_map_b`, etc…, which returns the first
+//! `Err` from the closure, or the transformed value with the other components untouched. As with
+//! `map_a`, the closure is only called if the component is present:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Raw = Some2<&'static str, String>;
+//!
+//! let raw = Raw::AB("42", "Alice".to_string());
+//! assert_eq!(raw.try_map_a(str::parse::<u64>), Ok(Some2::AB(42, "Alice".to_string())));
+//!
+//! let raw = Raw::A("forty-two");
+//! assert!(raw.try_map_a(str::parse::<u64>).is_err());
+//!
+//! let raw = Raw::B("Bob".to_string());
+//! assert_eq!(raw.try_map_a(str::parse::<u64>), Ok(Some2::B("Bob".to_string())));
+//! # let triple = Some3::<u8, &str, bool>::BC("7", true);
+//! # assert_eq!(triple.try_map_b(str::parse::<i8>), Ok(Some3::BC(7, true)));
+//! # assert_eq!(triple.try_map_c(|_| Err::<bool, _>("no")), Err("no"));
+//! # assert_eq!(triple.try_map_a(|_| Err::<u8, _>("no")), Ok(triple));
+//! ```
+//!
+//! For a lossy transform which may give `None`, `try_map_a_or`, `try_map_b_or`, etc… substitute
+//! `default` in that case, so the component is never removed and the result is never empty:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type Raw = Some2<&'static str, String>;
+//!
+//! let parse = |s: &str| s.parse::<u64>().ok();
+//! assert_eq!(Raw::A("42").try_map_a_or(0, parse), Some2::A(42));
+//! assert_eq!(Raw::A("forty-two").try_map_a_or(0, parse), Some2::A(0));
+//! # let bob = || "Bob".to_string();
+//! # assert_eq!(Raw::B(bob()).try_map_a_or(0, |_| unreachable!()), Some2::B(bob()));
+//! # assert_eq!(Raw::AB("x", bob()).try_map_b_or(0, |_| None), Some2::AB("x", 0));
+//! ```
+//!
+//! All components can be transformed at once with `map`, which takes one closure per type
+//! parameter and calls only the closures for present components. The closures are called in
+//! parameter order, so for [Some3::ABC] the `A` closure runs first, then `B`, then `C`:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(42, "Alice".to_string());
+//! let mapped: Some2<String, usize> = nid.map(|id| id.to_string(), |name| name.len());
+//! assert_eq!(mapped, Some2::AB("42".to_string(), 5));
+//!
+//! let calls = std::cell::RefCell::new(vec![]);
+//! let triple = Some3::<i64, &'static str, bool>::ABC(42, "x", false);
+//! let mapped = triple.map(
+//!     |a| { calls.borrow_mut().push('a'); a + 1 },
+//!     |b| { calls.borrow_mut().push('b'); b.len() },
+//!     |c| { calls.borrow_mut().push('c'); !c },
+//! );
+//! assert_eq!(mapped, Some3::ABC(43, 1, true));
+//! assert_eq!(calls.into_inner(), vec!['a', 'b', 'c']);
+//! ```
+//!
+//! To derive a new "someval" without consuming the original, `map_ref` passes each closure a
+//! reference instead, as a shorthand for `as_ref().map(…)`:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! let named = Some2::<String, Vec<u8>>::AB("Alice".to_string(), vec![1, 2, 3]);
+//! let lens: Some2<usize, usize> = named.map_ref(String::len, Vec::len);
+//! assert_eq!(lens, Some2::AB(5, 3));
+//! assert_eq!(named.a(), Some("Alice".to_string()));
+//!
+//! let triple = Some3::<String, Vec<u8>, bool>::BC(vec![7], true);
+//! assert_eq!(triple.map_ref(String::len, Vec::len, |c| !c), Some3::BC(1, false));
+//! # let firsts = triple.map_ref(|_| unreachable!(), |b| b[0], |&c| c);
+//! # assert_eq!(firsts, Some3::<(), _, _>::BC(7, true));
+//! ```
+//!
+//! When every type parameter is the same type, `map_all` transforms every present component with a
+//! single closure:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Range = Some2<i32, i32>;
+//!
+//! assert_eq!(Range::AB(-1, 2).map_all(|x| x * 10), Some2::AB(-10, 20));
+//! assert_eq!(Range::B(3).map_all(|x| x.to_string()), Some2::B("3".to_string()));
+//! # assert_eq!(Range::A(-4).map_all(i32::abs), Some2::A(4));
+//! # assert_eq!(Some3::<u8, u8, u8>::AC(1, 3).map_all(u32::from), Some3::AC(1u32, 3u32));
+//! ```
+//!
+//! Such a homogeneous "someval" can also be iterated as a non-empty list, via `IntoIterator` with
+//! the [PresentIter] iterator, or collected with `into_present_vec` (with the `std` feature). Both
+//! give the present components in parameter order:
+//!
+//! ```
+//! # use someval::Some3;
+//!
+//! type Triple = Some3<u8, u8, u8>;
+//!
+//! # #[cfg(feature = "std")]
+//! # {
+//! assert_eq!(Triple::ABC(1, 2, 3).into_present_vec(), vec![1, 2, 3]);
+//! assert_eq!(Triple::B(2).into_present_vec(), vec![2]);
+//! # assert_eq!(Triple::AC(1, 3).into_present_vec(), vec![1, 3]);
+//! # assert_eq!(someval::Some2::<u8, u8>::AB(1, 2).into_present_vec(), vec![1, 2]);
+//! # }
+//!
+//! let mut total = 0;
+//! for x in Triple::AC(1, 3) {
+//!     total += x;
+//! }
+//! assert_eq!(total, 4);
+//! # type Pair = someval::Some2<char, char>;
+//! # assert_eq!(Pair::A('a').into_iter().collect::<String>(), "a");
+//! # assert_eq!(Pair::B('b').into_iter().collect::<String>(), "b");
+//! # assert_eq!(Pair::AB('a', 'b').into_iter().collect::<String>(), "ab");
+//! # assert_eq!(Triple::BC(2, 3).into_iter().count(), 2);
+//! ```
+//!
+//! A homogeneous [Some2] with two equal components can be collapsed with `canonicalize`, which
+//! turns `AB(x, x)` into `A(x)` and leaves every other value unchanged. This is only meaningful
+//! when both type parameters are the same type, so that is required:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type Pair = Some2<u32, u32>;
+//!
+//! assert_eq!(Pair::AB(7, 7).canonicalize(), Pair::A(7));
+//! assert_eq!(Pair::AB(7, 8).canonicalize(), Pair::AB(7, 8));
+//! assert_eq!(Pair::B(7).canonicalize(), Pair::B(7));
+//! # assert_eq!(Pair::A(7).canonicalize(), Pair::A(7));
+//! ```
+//!
+//! To iterate by reference without consuming the "someval", use `present_refs`:
+//!
+//! ```
+//! # use someval::Some3;
+//!
+//! type Names = Some3<String, String, String>;
+//!
+//! let names = Names::AC("Alice".to_string(), "Carol".to_string());
+//! let lens: Vec<usize> = names.present_refs().map(String::len).collect();
+//! assert_eq!(lens, vec![5, 5]);
+//! assert_eq!(names.present_refs().next(), Some(&"Alice".to_string()));
+//! # let s = |x: &str| x.to_string();
+//! # for (names, expected) in [
+//! #     (Names::A(s("a")), vec!["a"]),
+//! #     (Names::B(s("b")), vec!["b"]),
+//! #     (Names::C(s("c")), vec!["c"]),
+//! #     (Names::AB(s("a"), s("b")), vec!["a", "b"]),
+//! #     (Names::AC(s("a"), s("c")), vec!["a", "c"]),
+//! #     (Names::BC(s("b"), s("c")), vec!["b", "c"]),
+//! #     (Names::ABC(s("a"), s("b"), s("c")), vec!["a", "b", "c"]),
+//! # ] {
+//! #     assert_eq!(names.present_refs().collect::<Vec<_>>(), expected);
+//! # }
+//! ```
+//!
+//! The iterator knows exactly how many components remain, so it implements [ExactSizeIterator],
+//! and it also implements [DoubleEndedIterator] to iterate in reverse:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Triple = Some3<u8, u8, u8>;
+//!
+//! let mut iter = Triple::ABC(1, 2, 3).into_iter();
+//! assert_eq!(iter.len(), 3);
+//! assert_eq!(iter.next(), Some(1));
+//! assert_eq!(iter.size_hint(), (2, Some(2)));
+//!
+//! let rev: Vec<u8> = Triple::AC(1, 3).into_iter().rev().collect();
+//! assert_eq!(rev, vec![3, 1]);
+//! # assert_eq!(Triple::B(2).into_iter().size_hint(), (1, Some(1)));
+//! # assert_eq!(Some2::<u8, u8>::AB(1, 2).into_iter().len(), 2);
+//! # assert_eq!(Some2::<u8, u8>::B(2).into_iter().rev().collect::<Vec<_>>(), vec![2]);
+//! # let mut iter = Triple::ABC(1, 2, 3).into_iter();
+//! # assert_eq!(iter.next_back(), Some(3));
+//! # assert_eq!(iter.next(), Some(1));
+//! # assert_eq!(iter.len(), 1);
+//! # assert_eq!(iter.next_back(), Some(2));
+//! # assert_eq!((iter.len(), iter.next(), iter.next_back()), (0, None, None));
+//! ```
+//!
+//! Since at least one component is present, `first` gives the first present component in
+//! parameter order directly, rather than as an `Option`. So `A` takes precedence over `B`, and so
+//! on:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Pair = Some2<&'static str, &'static str>;
+//!
+//! let name: &str = Pair::AB("Alice", "Bob").first();
+//! assert_eq!(name, "Alice");
+//! assert_eq!(Pair::B("Bob").first(), "Bob");
+//! # assert_eq!(Pair::A("Alice").first(), "Alice");
+//! # assert_eq!(Some3::<u8, u8, u8>::BC(2, 3).first(), 2);
+//! # assert_eq!(Some3::<u8, u8, u8>::C(3).first(), 3);
+//! ```
+//!
+//! Likewise `reduce` combines the present components left to right in parameter order with a
+//! binary operation, without an initial value (similar to [Iterator::reduce], but infallible):
+//!
+//! ```
+//! # use someval::Some3;
+//!
+//! type Triple = Some3<u32, u32, u32>;
+//!
+//! assert_eq!(Triple::ABC(1, 2, 3).reduce(|x, y| x + y), 6);
+//! assert_eq!(Triple::AC(1, 3).reduce(u32::max), 3);
+//! assert_eq!(Triple::B(2).reduce(|_, _| unreachable!()), 2);
+//! # assert_eq!(Triple::ABC(1, 2, 3).reduce(|x, y| x * 10 + y), 123);
+//! ```
+//!
+//! For a fallible accumulation, `try_fold` starts from an initial value and stops at the first
+//! `Err` (similar to [Iterator::try_fold]):
+//!
+//! ```
+//! # use someval::Some3;
+//!
+//! type Triple = Some3<u8, u8, u8>;
+//!
+//! let checked_sum = |t: Triple| t.try_fold(0u8, |acc, x| acc.checked_add(x).ok_or(x));
+//! assert_eq!(checked_sum(Triple::ABC(1, 2, 3)), Ok(6));
+//! assert_eq!(checked_sum(Triple::AC(1, 3)), Ok(4));
+//!
+//! let mut seen = vec![];
+//! let res = Triple::ABC(1, 200, 3).try_fold(0, |acc, x| {
+//!     seen.push(x);
+//!     if x > 100 { Err(x) } else { Ok(acc + x) }
+//! });
+//! assert_eq!(res, Err(200));
+//! assert_eq!(seen, vec![1, 200]);
+//! # assert_eq!(checked_sum(Triple::ABC(200, 60, 1)), Err(60));
+//! ```
+//!
+//! When each component converts via [Into], `map_into` converts them all without closures:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! let nid = Some2::<u8, &str>::AB(42, "Alice");
+//! let wide: Some2<u32, String> = nid.map_into();
+//! assert_eq!(wide, Some2::AB(42, "Alice".to_string()));
+//! # let wide: Some2<u32, String> = Some2::<u8, &str>::B("x").map_into();
+//! # assert_eq!(wide, Some2::B("x".to_string()));
+//! # let wide: Some3<i64, f64, String> = Some3::<i8, f32, char>::AC(-1, 'x').map_into();
+//! # assert_eq!(wide, Some3::AC(-1, "x".to_string()));
+//! ```
+//!
+//! Likewise `try_map_into` converts each component via [TryInto], stopping at the first failure in
+//! parameter order. The error is a "someval" of the conversion error types, holding only the
+//! failed component, so its variant identifies the slot which failed:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Wide = Some2<u32, i64>;
+//!
+//! let narrow: Result<Some2<u8, i8>, _> = Wide::AB(42, -1).try_map_into();
+//! assert_eq!(narrow, Ok(Some2::AB(42, -1)));
+//!
+//! let narrow: Result<Some2<u8, i8>, _> = Wide::AB(42, 1000).try_map_into();
+//! let err = narrow.unwrap_err();
+//! assert_eq!(err.variant_name(), "B");
+//! assert!(err.is_b());
+//! # let narrow: Result<Some2<u8, i8>, _> = Wide::AB(1000, 1000).try_map_into();
+//! # assert!(narrow.unwrap_err().is_a());
+//! # let narrow: Result<Some3<u8, i8, u16>, _> = Some3::<u32, i64, u64>::C(7).try_map_into();
+//! # assert_eq!(narrow, Ok(Some3::C(7)));
+//! ```
+//!
+//! With `apply`, the closures are themselves given as a "someval" of the same arity. Each
+//! component is transformed by the closure in the same position, and only when both are present.
+//! Components without a matching closure, and closures without a matching component, are dropped,
+//! so the result is `None` when no positions line up:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let fns = Some2::<fn(u64) -> u64, fn(String) -> usize>::AB(|id| id + 1, |name| name.len());
+//! assert_eq!(NameId::AB(42, "Alice".to_string()).apply(fns), Some(Some2::AB(43, 5)));
+//! assert_eq!(NameId::A(42).apply(fns), Some(Some2::A(43)));
+//!
+//! let fns = Some2::<fn(u64) -> u64, fn(String) -> usize>::B(|name| name.len());
+//! assert_eq!(NameId::A(42).apply(fns), None);
+//! # assert_eq!(NameId::AB(42, "Bob".to_string()).apply(fns), Some(Some2::B(3)));
+//! # let fns = someval::Some3::<fn(u8) -> u8, fn(u8) -> u8, fn(u8) -> u8>::AC(|a| a, |c| c * 2);
+//! # assert_eq!(someval::Some3::BC(1, 2).apply(fns), Some(someval::Some3::C(4)));
+//! ```
+//!
+//! A "someval" can be reduced to a single value with `fold`, which converts each present
+//! component with its closure, then combines the results left to right in parameter order. The
+//! `combine` closure is only called when more than one component is present:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let describe = |nid: NameId| nid.fold(
+//!     |id| format!("#{}", id),
+//!     |name| name,
+//!     |x, y| format!("{} {}", x, y),
+//! );
+//! assert_eq!(describe(NameId::A(42)), "#42");
+//! assert_eq!(describe(NameId::B("Bob".to_string())), "Bob");
+//! assert_eq!(describe(NameId::AB(13, "Bob".to_string())), "#13 Bob");
+//!
+//! let triple = Some3::<u8, u16, u32>::ABC(1, 2, 3);
+//! let sum = triple.fold(u64::from, u64::from, u64::from, |x, y| x + y);
+//! assert_eq!(sum, 6);
+//! # let triple = Some3::<u8, u16, u32>::AC(1, 3);
+//! # let v = |x: u32| vec![x];
+//! # let cat = |mut x: Vec<u32>, y| { x.extend(y); x };
+//! # assert_eq!(triple.fold(|a| v(a.into()), |b| v(b.into()), v, cat), vec![1, 3]);
+//! ```
+//!
+//! ## Combining "somevals"
+//!
+//! Two "somevals" of the same type can be merged with `or_merge`, which takes each component
+//! from `self` if present, otherwise from `other` (similar to [Option::or]). Because both inputs
+//! have at least one component, so does the result. When both inputs have a component, the one
+//! from `self` wins and the one from `other` is dropped:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::A(42).or_merge(NameId::B("Alice".to_string()));
+//! assert_eq!(nid, NameId::AB(42, "Alice".to_string()));
+//!
+//! let nid = NameId::AB(42, "Alice".to_string()).or_merge(NameId::A(7));
+//! assert_eq!(nid, NameId::AB(42, "Alice".to_string()));
+//! # use Some2::{A, B, AB};
+//! # let merge = |x: Some2<u8, u8>, y| x.or_merge(y);
+//! # assert_eq!(merge(A(1), A(2)), A(1));
+//! # assert_eq!(merge(A(1), B(2)), AB(1, 2));
+//! # assert_eq!(merge(A(1), AB(2, 3)), AB(1, 3));
+//! # assert_eq!(merge(B(1), A(2)), AB(2, 1));
+//! # assert_eq!(merge(B(1), B(2)), B(1));
+//! # assert_eq!(merge(B(1), AB(2, 3)), AB(2, 1));
+//! # assert_eq!(merge(AB(1, 2), A(3)), AB(1, 2));
+//! # assert_eq!(merge(AB(1, 2), B(3)), AB(1, 2));
+//! # assert_eq!(merge(AB(1, 2), AB(3, 4)), AB(1, 2));
+//! ```
+//!
+//! To resolve overlapping components some other way, `merge_with` takes one closure per type
+//! parameter, which is only called when both inputs have that component. Otherwise the present
+//! component, if any, is taken directly:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type Stats = Some2<u32, u32>;
+//!
+//! let sum_max = |x: Stats, y| x.merge_with(y, |a, b| a + b, u32::max);
+//! assert_eq!(sum_max(Stats::AB(1, 5), Stats::AB(2, 3)), Stats::AB(3, 5));
+//! assert_eq!(sum_max(Stats::A(1), Stats::AB(2, 3)), Stats::AB(3, 3));
+//! assert_eq!(sum_max(Stats::A(1), Stats::B(3)), Stats::AB(1, 3));
+//! # use Some2::{A, B, AB};
+//! # let merge = |x: Some2<u8, u8>, y| x.merge_with(y, |a, b| a * 10 + b, |a, b| a * 10 + b);
+//! # assert_eq!(merge(A(1), A(2)), A(12));
+//! # assert_eq!(merge(A(1), B(2)), AB(1, 2));
+//! # assert_eq!(merge(A(1), AB(2, 3)), AB(12, 3));
+//! # assert_eq!(merge(B(1), A(2)), AB(2, 1));
+//! # assert_eq!(merge(B(1), B(2)), B(12));
+//! # assert_eq!(merge(B(1), AB(2, 3)), AB(2, 13));
+//! # assert_eq!(merge(AB(1, 2), A(3)), AB(13, 2));
+//! # assert_eq!(merge(AB(1, 2), B(3)), AB(1, 23));
+//! # assert_eq!(merge(AB(1, 2), AB(3, 4)), AB(13, 24));
+//! ```
+//!
+//! To instead require that no component is present in both inputs, `xor_merge` returns a
+//! [ConflictError] when they overlap, rather than dropping a component:
+//!
+//! ```
+//! # use someval::{ConflictError, Some2};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::A(42).xor_merge(NameId::B("Alice".to_string()));
+//! assert_eq!(nid, Ok(NameId::AB(42, "Alice".to_string())));
+//!
+//! let nid = NameId::AB(42, "Alice".to_string()).xor_merge(NameId::A(7));
+//! assert_eq!(nid, Err(ConflictError));
+//! # use Some2::{A, B, AB};
+//! # let merge = |x: Some2<u8, u8>, y| x.xor_merge(y);
+//! # assert_eq!(merge(A(1), A(2)), Err(ConflictError));
+//! # assert_eq!(merge(B(1), A(2)), Ok(AB(2, 1)));
+//! # assert_eq!(merge(B(1), B(2)), Err(ConflictError));
+//! # assert_eq!(merge(B(1), AB(2, 3)), Err(ConflictError));
+//! # assert_eq!(merge(AB(1, 2), B(3)), Err(ConflictError));
+//! # type Triple = someval::Some3<i64, &'static str, bool>;
+//! # assert_eq!(Triple::AC(1, true).xor_merge(Triple::B("x")), Ok(Triple::ABC(1, "x", true)));
+//! # assert_eq!(Triple::AC(1, true).xor_merge(Triple::BC("x", false)), Err(ConflictError));
+//! # assert_eq!(ConflictError.to_string(), "the same component is present in both values");
+//! ```
+//!
+//! A [Some2] whose components are both the same [Some2] type can be flattened with `flatten`. A
+//! single inner value is returned as is, and when both are present they are combined with
+//! `or_merge`, so each component is taken from the `A` value if present, otherwise from `B`:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nested = Some2::<NameId, NameId>::B(NameId::A(42));
+//! assert_eq!(nested.flatten(), NameId::A(42));
+//!
+//! let nested = Some2::AB(NameId::A(42), NameId::AB(7, "Alice".to_string()));
+//! assert_eq!(nested.flatten(), NameId::AB(42, "Alice".to_string()));
+//! # use Some2::{A, B, AB};
+//! # type Nested = Some2<Some2<u8, u8>, Some2<u8, u8>>;
+//! # assert_eq!(Nested::A(A(1)).flatten(), A(1));
+//! # assert_eq!(Nested::A(AB(1, 2)).flatten(), AB(1, 2));
+//! # assert_eq!(Nested::B(B(2)).flatten(), B(2));
+//! # assert_eq!(Nested::AB(A(1), A(2)).flatten(), A(1));
+//! # assert_eq!(Nested::AB(A(1), B(2)).flatten(), AB(1, 2));
+//! # assert_eq!(Nested::AB(B(1), A(2)).flatten(), AB(2, 1));
+//! # assert_eq!(Nested::AB(B(1), B(2)).flatten(), B(1));
+//! # assert_eq!(Nested::AB(AB(1, 2), AB(3, 4)).flatten(), AB(1, 2));
+//! ```
+//!
+//! Two [Some2] values of any types can be combined into a [Some4] with `zip`, preserving which
+//! components are present:
+//!
+//! ```
+//! # use someval::{Some2, Some4};
+//!
+//! type NameId = Some2<u64, String>;
+//! type Location = Some2<f32, &'static str>;
+//!
+//! let nid = NameId::A(42);
+//! let loc = Location::AB(1.5, "Lisbon");
+//! assert_eq!(nid.zip(loc), Some4::ACD(42, 1.5, "Lisbon"));
+//! # use Some2::{A, B, AB};
+//! # let zip = |x: Some2<u8, u8>, y: Some2<u8, u8>| x.zip(y);
+//! # assert_eq!(zip(A(1), A(2)), Some4::AC(1, 2));
+//! # assert_eq!(zip(A(1), B(2)), Some4::AD(1, 2));
+//! # assert_eq!(zip(A(1), AB(2, 3)), Some4::ACD(1, 2, 3));
+//! # assert_eq!(zip(B(1), A(2)), Some4::BC(1, 2));
+//! # assert_eq!(zip(B(1), B(2)), Some4::BD(1, 2));
+//! # assert_eq!(zip(B(1), AB(2, 3)), Some4::BCD(1, 2, 3));
+//! # assert_eq!(zip(AB(1, 2), A(3)), Some4::ABC(1, 2, 3));
+//! # assert_eq!(zip(AB(1, 2), B(3)), Some4::ABD(1, 2, 3));
+//! # assert_eq!(zip(AB(1, 2), AB(3, 4)), Some4::ABCD(1, 2, 3, 4));
+//! ```
+//!
+//! A [Some2] of pairs can be split into two [Some2] values with `unzip`, which takes the first
+//! element of each present pair into the first result, and the second into the second result. So
+//! a `Some2<(A, C), (B, D)>` becomes a `(Some2<A, B>, Some2<C, D>)`, and both results have the
+//! same variant as the input:
+//!
+//! ```
+//! # use someval::Some2;
+//!
+//! type Labeled = Some2<(u64, &'static str), (String, &'static str)>;
+//!
+//! let labeled = Labeled::AB((42, "id"), ("Alice".to_string(), "name"));
+//! let (nid, labels) = labeled.unzip();
+//! assert_eq!(nid, Some2::AB(42, "Alice".to_string()));
+//! assert_eq!(labels, Some2::AB("id", "name"));
+//!
+//! assert_eq!(Labeled::A((42, "id")).unzip(), (Some2::A(42), Some2::A("id")));
+//! # let bob = Labeled::B(("Bob".to_string(), "name"));
+//! # assert_eq!(bob.unzip(), (Some2::B("Bob".to_string()), Some2::B("name")));
+//! ```
+//!
+//! A [Some2] can be widened into a [Some3] via `From`, with the `C` component absent:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let values: Vec<Some3<u64, String, bool>> = vec![
+//!     NameId::A(42).into(),
+//!     NameId::B("Alice".to_string()).into(),
+//!     NameId::AB(13, "Bob".to_string()).into(),
+//!     Some3::C(true),
+//! ];
+//! assert_eq!(values[0], Some3::A(42));
+//! assert_eq!(values[1], Some3::B("Alice".to_string()));
+//! assert_eq!(values[2], Some3::AB(13, "Bob".to_string()));
+//! ```
+//!
+//! The same widening is available as the explicit method `widen_c`, with the type of the absent
+//! component given by a turbofish or inferred from context. Likewise `widen_a` and `widen_b` insert
+//! the absent component before or between the existing ones, shifting them along:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(42, "Alice".to_string());
+//! assert_eq!(nid.clone().widen_c::<bool>(), Some3::AB(42, "Alice".to_string()));
+//!
+//! let shifted: Some3<bool, u64, String> = nid.clone().widen_a();
+//! assert_eq!(shifted, Some3::BC(42, "Alice".to_string()));
+//!
+//! let split: Some3<u64, bool, String> = nid.widen_b();
+//! assert_eq!(split, Some3::AC(42, "Alice".to_string()));
+//! # assert_eq!(NameId::A(1).widen_a::<()>(), Some3::B(1));
+//! # assert_eq!(NameId::B("x".to_string()).widen_a::<()>(), Some3::C("x".to_string()));
+//! # assert_eq!(NameId::A(1).widen_b::<()>(), Some3::A(1));
+//! # assert_eq!(NameId::B("x".to_string()).widen_b::<()>(), Some3::C("x".to_string()));
+//! # assert_eq!(NameId::B("x".to_string()).widen_c::<()>(), Some3::B("x".to_string()));
+//! ```
+//!
+//! Conversely, a [Some3] without a `C` component can be narrowed into a [Some2] with
+//! `try_into_some2`. If `C` is present, the original value is returned in the `Err` so no data is
+//! lost:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! assert_eq!(Triple::AB(42, "x").try_into_some2(), Ok(Some2::AB(42, "x")));
+//! assert_eq!(Triple::AC(42, false).try_into_some2(), Err(Triple::AC(42, false)));
+//! # assert_eq!(Triple::A(1).try_into_some2(), Ok(Some2::A(1)));
+//! # assert_eq!(Triple::B("x").try_into_some2(), Ok(Some2::B("x")));
+//! # assert_eq!(Triple::C(true).try_into_some2(), Err(Triple::C(true)));
+//! # assert_eq!(Triple::BC("x", true).try_into_some2(), Err(Triple::BC("x", true)));
+//! # assert_eq!(Triple::ABC(1, "x", true).try_into_some2(), Err(Triple::ABC(1, "x", true)));
+//! ```
+//!
+//! A [Some3] can also project out one type parameter with `without_a`, `without_b`, or
+//! `without_c`, which drop that component and give the remaining [Some2], or `None` if the dropped
+//! component was the only one present:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! assert_eq!(Triple::ABC(42, "x", true).without_a(), Some(Some2::AB("x", true)));
+//! assert_eq!(Triple::AC(42, false).without_b(), Some(Some2::AB(42, false)));
+//! assert_eq!(Triple::A(42).without_a(), None);
+//! # assert_eq!(Triple::AB(42, "x").without_a(), Some(Some2::A("x")));
+//! # assert_eq!(Triple::B("x").without_b(), None);
+//! # assert_eq!(Triple::C(true).without_c(), None);
+//! # assert_eq!(Triple::C(true).without_a(), Some(Some2::B(true)));
+//! # assert_eq!(Triple::BC("x", true).without_c(), Some(Some2::B("x")));
+//! ```
+//!
+//! To keep the dropped component, `split_a`, `split_b`, and `split_c` return it separately
+//! alongside the remaining [Some2]. At least one of the two is always present:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! assert_eq!(Triple::ABC(42, "x", true).split_a(), (Some(42), Some(Some2::AB("x", true))));
+//! assert_eq!(Triple::A(42).split_a(), (Some(42), None));
+//! assert_eq!(Triple::BC("x", true).split_a(), (None, Some(Some2::AB("x", true))));
+//! # assert_eq!(Triple::AB(42, "x").split_b(), (Some("x"), Some(Some2::A(42))));
+//! # assert_eq!(Triple::C(true).split_c(), (Some(true), None));
+//! # assert_eq!(Triple::AC(1, true).split_b(), (None, Some(Some2::AB(1, true))));
+//! ```
+//!
+//! The type parameters of a [Some2] can be swapped with `swap`, and those of a [Some3] rotated
+//! with `rotate`, which moves each component one position left and the first to the end, so a
+//! `Some3<A, B, C>` becomes a `Some3<B, C, A>`:
+//!
+//! ```
+//! # use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, &'static str>;
+//!
+//! assert_eq!(NameId::A(42).swap(), Some2::B(42));
+//! assert_eq!(NameId::AB(42, "Alice").swap(), Some2::AB("Alice", 42));
+//! # assert_eq!(NameId::B("Alice").swap(), Some2::A("Alice"));
+//!
+//! type Triple = Some3<i64, &'static str, bool>;
+//!
+//! let rotated: Some3<&'static str, bool, i64> = Triple::AB(42, "x").rotate();
+//! assert_eq!(rotated, Some3::AC("x", 42));
+//! assert_eq!(rotated.rotate().rotate(), Triple::AB(42, "x"));
+//! # assert_eq!(Triple::A(1).rotate(), Some3::C(1));
+//! # assert_eq!(Triple::B("x").rotate(), Some3::A("x"));
+//! # assert_eq!(Triple::C(true).rotate(), Some3::B(true));
+//! # assert_eq!(Triple::AC(1, true).rotate(), Some3::BC(true, 1));
+//! # assert_eq!(Triple::BC("x", true).rotate(), Some3::AB("x", true));
+//! # assert_eq!(Triple::ABC(1, "x", true).rotate(), Some3::ABC("x", true, 1));
+//! ```
+//!
+//! # Optional features
+//!
+//! ## `std`
+//!
+//! The `std` feature is enabled by default. Without it, the crate is `#![no_std]` and everything
+//! except the [core::error::Error] impls, `into_present_vec`, `into_owned`, and `to_label_map` is
+//! available.
+//!
+//! ## `arbitrary`
+//!
+//! The `arbitrary` feature implements `arbitrary::Arbitrary` for every "someval" type, e.g. for
+//! fuzzing. A non-empty subset of components is chosen, then each present component is generated
+//! from its own `Arbitrary` impl, so the all-absent state is never produced, even from exhausted
+//! input:
+//!
+//! ```
+//! # #[cfg(feature = "arbitrary")]
+//! # {
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use someval::Some3;
+//!
+//! let mut u = Unstructured::new(&[0x2a, 0x07, 0x01, 0x02, 0x03]);
+//! let val = Some3::<u8, u16, bool>::arbitrary(&mut u).unwrap();
+//! assert!(val.count() >= 1);
+//!
+//! let mut empty = Unstructured::new(&[]);
+//! let val = Some3::<u8, u16, bool>::arbitrary(&mut empty).unwrap();
+//! assert!(val.count() >= 1);
+//! # }
+//! ```
+//!
+//! ## `borsh`
+//!
+//! The `borsh` feature implements `BorshSerialize` and `BorshDeserialize` for every "someval"
+//! type. Values are encoded as a one-byte tag, which is the `present_mask`, followed by the present
+//! components in parameter order. Deserialization rejects an empty or unknown tag:
+//!
+//! ```
+//! # #[cfg(feature = "borsh")]
+//! # {
+//! use someval::Some3;
+//!
+//! type Triple = Some3<u8, u16, bool>;
+//!
+//! let bytes = borsh::to_vec(&Triple::AC(7, true)).unwrap();
+//! assert_eq!(bytes, [0b101, 7, 1]);
+//! assert_eq!(borsh::from_slice::<Triple>(&bytes).unwrap(), Triple::AC(7, true));
+//!
+//! assert!(borsh::from_slice::<Triple>(&[0b000]).is_err());
+//! assert!(borsh::from_slice::<Triple>(&[0b1001, 7]).is_err());
+//! # assert!(borsh::from_slice::<Triple>(&[]).is_err());
+//! # assert!(borsh::from_slice::<Triple>(&[0b011, 7]).is_err());
+//! # assert!(borsh::from_slice::<Triple>(&[0b001, 7, 0]).is_err());
+//! # type Strings = Some3<i64, String, bool>;
+//! # let s = || "x".to_string();
+//! # for triple in [
+//! #     Strings::A(1), Strings::B(s()), Strings::C(true), Strings::AB(1, s()),
+//! #     Strings::AC(1, true), Strings::BC(s(), true), Strings::ABC(1, s(), true),
+//! # ] {
+//! #     let bytes = borsh::to_vec(&triple).unwrap();
+//! #     assert_eq!(bytes[0], triple.present_mask());
+//! #     assert_eq!(borsh::from_slice::<Strings>(&bytes).unwrap(), triple);
+//! # }
+//! # type Pair = someval::Some2<u32, u32>;
+//! # for pair in [Pair::A(1), Pair::B(2), Pair::AB(1, 2)] {
+//! #     let bytes = borsh::to_vec(&pair).unwrap();
+//! #     assert_eq!(borsh::from_slice::<Pair>(&bytes).unwrap(), pair);
+//! # }
+//! # }
+//! ```
+//!
+//! ## `either`
+//!
+//! The `either` feature adds `try_into_either` to [Some2], which converts a single-component value
+//! into an `either::Either`. A [Some2::AB] value is returned unchanged in the `Err`:
+//!
+//! ```
+//! # #[cfg(feature = "either")]
+//! # {
+//! use either::Either;
+//! use someval::Some2;
+//!
+//! type NameId = Some2<u64, &'static str>;
+//!
+//! assert_eq!(NameId::A(42).try_into_either(), Ok(Either::Left(42)));
+//! assert_eq!(NameId::B("Bob").try_into_either(), Ok(Either::Right("Bob")));
+//! assert_eq!(NameId::AB(13, "Bob").try_into_either(), Err(NameId::AB(13, "Bob")));
+//! # }
+//! ```
+//!
+//! ## `proptest`
+//!
+//! The `proptest` feature provides a strategy function for each "someval" type, such as
+//! `some2_strategy`, composed from a strategy for each type parameter. It chooses a non-empty
+//! subset of components, then generates each present component from its strategy:
+//!
+//! ```
+//! # #[cfg(feature = "proptest")]
+//! # {
+//! use proptest::prelude::*;
+//! use proptest::test_runner::TestRunner;
+//! use someval::{some2_strategy, Some2};
+//!
+//! let mut runner = TestRunner::default();
+//! runner
+//!     .run(&some2_strategy(any::<u64>(), "[a-z]*"), |nid| {
+//!         let (optid, optname) = nid.clone().into();
+//!         prop_assert_eq!(Some2::try_from((optid, optname)), Ok(nid));
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! # }
+//! ```
+//!
+//! ## `serde`
+//!
+//! The `serde` feature implements `Serialize` and `Deserialize` for every "someval" type. Values
+//! are represented as a map of the present components keyed by their lowercase parameter name:
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use someval::{Some2, Some3};
+//!
+//! type NameId = Some2<u64, String>;
+//!
+//! let nid = NameId::AB(13, "Bob".to_string());
+//! assert_eq!(serde_json::to_string(&nid).unwrap(), r#"{"a":13,"b":"Bob"}"#);
+//!
+//! let nid: NameId = serde_json::from_str(r#"{"a":42}"#).unwrap();
+//! assert_eq!(nid, NameId::A(42));
+//!
+//! // The all-absent case is rejected:
+//! let err = serde_json::from_str::<NameId>("{}").unwrap_err();
+//! assert!(err.to_string().contains("no value of any accepted type present"));
+//! # let s = || "x".to_string();
+//! # for nid in [NameId::A(42), NameId::B(s()), NameId::AB(13, s())] {
+//! #     let json = serde_json::to_string(&nid).unwrap();
+//! #     assert_eq!(serde_json::from_str::<NameId>(&json).unwrap(), nid);
+//! # }
+//! # type Triple = Some3<i64, String, bool>;
+//! # for triple in [
+//! #     Triple::A(1), Triple::B(s()), Triple::C(true), Triple::AB(1, s()), Triple::AC(1, true),
+//! #     Triple::BC(s(), true), Triple::ABC(1, s(), true),
+//! # ] {
+//! #     let json = serde_json::to_string(&triple).unwrap();
+//! #     assert_eq!(serde_json::from_str::<Triple>(&json).unwrap(), triple);
+//! # }
+//! # assert_eq!(serde_json::to_string(&Triple::AC(1, true)).unwrap(), r#"{"a":1,"c":true}"#);
+//! # assert!(serde_json::from_str::<Triple>(r#"{"a":1,"a":2}"#).is_err());
+//! # assert!(serde_json::from_str::<Triple>(r#"{"d":1}"#).is_err());
+//! # }
+//! ```
+//!
+//! The representation is not tied to JSON. Other self-describing formats such as CBOR (via
+//! `ciborium`) round-trip the same way, and keys are also accepted as bytes or as the component
+//! index in parameter order, for formats which encode identifiers that way:
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use serde::de::{value::MapDeserializer, Deserialize};
+//! use someval::Some3;
+//!
+//! type Triple = Some3<i64, String, bool>;
+//!
+//! let triple = Triple::AC(13, true);
+//! let mut cbor = vec![];
+//! ciborium::into_writer(&triple, &mut cbor).unwrap();
+//! assert_eq!(ciborium::from_reader::<Triple, _>(&cbor[..]).unwrap(), triple);
+//!
+//! type Ints = Some3<i64, i64, i64>;
+//! type DeError = serde::de::value::Error;
+//!
+//! let by_bytes = MapDeserializer::<_, DeError>::new([(&b"a"[..], 1), (&b"c"[..], 3)].into_iter());
+//! assert_eq!(Ints::deserialize(by_bytes).unwrap(), Ints::AC(1, 3));
+//!
+//! let by_index = MapDeserializer::<_, DeError>::new([(1u64, 2), (2u64, 3)].into_iter());
+//! assert_eq!(Ints::deserialize(by_index).unwrap(), Ints::BC(2, 3));
+//! # let s = || "x".to_string();
+//! # for triple in [
+//! #     Triple::A(1), Triple::B(s()), Triple::C(false), Triple::BC(s(), false),
+//! #     Triple::ABC(1, s(), true),
+//! # ] {
+//! #     let mut cbor = vec![];
+//! #     ciborium::into_writer(&triple, &mut cbor).unwrap();
+//! #     assert_eq!(ciborium::from_reader::<Triple, _>(&cbor[..]).unwrap(), triple);
+//! # }
+//! # let bad = MapDeserializer::<_, DeError>::new([(&b"d"[..], 1)].into_iter());
+//! # assert!(Ints::deserialize(bad).unwrap_err().to_string().contains("unknown field `d`"));
+//! # let bad = MapDeserializer::<_, DeError>::new([(3u64, 1)].into_iter());
+//! # assert!(Ints::deserialize(bad).unwrap_err().to_string().contains("unknown field index `3`"));
+//! # }
+//! ```
+//!
+//! ## `rkyv`
+//!
+//! The `rkyv` feature derives `rkyv`'s `Archive`, `Serialize`, and `Deserialize` for every
+//! "someval" type, for zero-copy access to archived data. The archived form preserves the variant
+//! and its present payloads. Since the empty state has no variant, checked access to a corrupt
+//! archive returns an error rather than panicking:
+//!
+//! ```
+//! # #[cfg(all(feature = "rkyv", feature = "std"))]
+//! # {
+//! use rkyv::rancor::Error;
+//! use someval::Some2;
+//!
+//! type Pair = Some2<u32, u32>;
+//!
+//! let bytes = rkyv::to_bytes::<Error>(&Pair::AB(13, 42)).unwrap();
+//! assert_eq!(rkyv::from_bytes::<Pair, Error>(&bytes).unwrap(), Pair::AB(13, 42));
+//!
+//! // Clobber the variant tag:
+//! let mut corrupt = bytes.to_vec();
+//! corrupt[0] = 0xff;
+//! assert!(rkyv::from_bytes::<Pair, Error>(&corrupt).is_err());
+//! # for pair in [Pair::A(1), Pair::B(2)] {
+//! #     let bytes = rkyv::to_bytes::<Error>(&pair).unwrap();
+//! #     assert_eq!(rkyv::from_bytes::<Pair, Error>(&bytes).unwrap(), pair);
+//! # }
+//! # type Triple = someval::Some3<i64, String, bool>;
+//! # let s = || "x".to_string();
+//! # for triple in [
+//! #     Triple::A(1), Triple::B(s()), Triple::C(true), Triple::AB(1, s()), Triple::AC(1, true),
+//! #     Triple::BC(s(), true), Triple::ABC(1, s(), true),
+//! # ] {
+//! #     let bytes = rkyv::to_bytes::<Error>(&triple).unwrap();
+//! #     assert_eq!(rkyv::from_bytes::<Triple, Error>(&bytes).unwrap(), triple);
+//! # }
+//! # }
+//! ```
+#![cfg_attr(not(feature = "std"), no_std)]
+// The `rkyv` derive emits private helper types named after our `ABC`-style variants:
+#![cfg_attr(feature = "rkyv", allow(clippy::upper_case_acronyms))]
+
+#[macro_use]
+mod macros;
+
+mod display;
+mod error;
+mod iter;
+mod replace;
+mod slot;
+mod some2;
+mod some3;
+mod some4;
+mod some5;
+mod some6;
+mod some7;
+mod some8;
+
+pub use self::error::{AllAbsent, ConflictError};
+pub use self::iter::PresentIter;
+pub use self::slot::Slot;
+pub use self::some2::{combine2, partition_by_presence, PartitionStats, Some2, Some2Presence};
+pub use self::some3::{combine3, Some3, Some3Presence};
+pub use self::some4::{Some4, Some4Presence};
+pub use self::some5::{Some5, Some5Presence};
+pub use self::some6::{Some6, Some6Presence};
+pub use self::some7::{Some7, Some7Presence};
+pub use self::some8::{Some8, Some8Presence};
+#[doc(hidden)]
+pub use pastey as __paste;
 
-pub use self::some2::Some2;
-pub use self::some3::Some3;
+#[cfg(feature = "proptest")]
+pub use self::{
+    some2::some2_strategy, some3::some3_strategy, some4::some4_strategy, some5::some5_strategy,
+    some6::some6_strategy, some7::some7_strategy, some8::some8_strategy,
+};
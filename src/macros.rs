@@ -0,0 +1,1239 @@
+/// Define a "someval" type given its name, type parameters, and variants.
+///
+/// Each variant lists the type parameters of its present components in parameter order, e.g.:
+///
+/// ```text
+/// some_n! {
+///     Some2<A, B> {
+///         A(A),
+///         B(B),
+///         AB(A, B),
+///     }
+/// }
+/// ```
+///
+/// Payload bindings in generated code are the lowercase type parameter names, so the
+/// per-component code for `A` uses the binding `a`, `B` uses `b`, etc…
+macro_rules! some_n {
+    ( $name:ident < $( $T:ident ),+ > { $( $Var:ident ( $( $V:ident ),+ ) ),+ $(,)? } ) => {
+        ::pastey::paste! {
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            #[cfg_attr(
+                feature = "rkyv",
+                derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)
+            )]
+            pub enum $name<$( $T ),+> {
+                $( $Var($( $V ),+), )+
+            }
+
+            #[doc = concat!("Which components of a [", stringify!($name), "] are present")]
+            #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+            pub struct [<$name Presence>] {
+                $( pub [<$T:lower>]: bool, )+
+            }
+
+            // The `present_mask` of each variant:
+            $( const [<$Var _VARIANT_MASK>]: u8 = 0 $( | [<$V _MASK>] )+; )+
+
+            impl<$( $T ),+> $name<$( $T ),+> {
+                #[must_use]
+                #[allow(clippy::too_many_arguments)]
+                pub fn try_from_options($( [<$T:lower>]: Option<$T> ),+) -> Option<Self> {
+                    let mask = 0 $( | if [<$T:lower>].is_some() { [<$T _MASK>] } else { 0 } )+;
+                    match mask {
+                        // Exactly this variant's components are present, so `?` never returns:
+                        $( [<$Var _VARIANT_MASK>] => Some($name::$Var($( [<$V:lower>]? ),+)), )+
+                        _ => None,
+                    }
+                }
+
+                // Every input is already evaluated, so this only selects the first `Err`:
+                #[allow(clippy::too_many_arguments)]
+                pub fn try_from_results<Error>(
+                    $( [<$T:lower>]: Result<Option<$T>, Error> ),+
+                ) -> Result<Option<Self>, Error> {
+                    Ok(Self::try_from_options($( [<$T:lower>]? ),+))
+                }
+
+                pub fn from_parts(
+                    ($( [<opt $T:lower>] ),+): ($( Option<$T> ),+),
+                ) -> Result<Self, $crate::AllAbsent> {
+                    Self::try_from_options($( [<opt $T:lower>] ),+).ok_or($crate::AllAbsent)
+                }
+
+                #[must_use]
+                #[allow(clippy::too_many_arguments)]
+                pub fn from_options_or<Fallback>(
+                    $( [<$T:lower>]: Option<$T>, )+
+                    fallback: Fallback,
+                ) -> Self
+                where
+                    Fallback: FnOnce() -> Self,
+                {
+                    Self::try_from_options($( [<$T:lower>] ),+).unwrap_or_else(fallback)
+                }
+
+                // Construct from options which are known to include at least one value:
+                #[allow(clippy::too_many_arguments)]
+                pub(crate) fn from_present_options($( [<$T:lower>]: Option<$T> ),+) -> Self {
+                    Self::try_from_options($( [<$T:lower>] ),+)
+                        .expect("at least one component is present")
+                }
+
+                #[must_use]
+                pub fn as_ref(&self) -> $name<$( &$T ),+> {
+                    match self {
+                        $( $name::$Var($( [<$V:lower>] ),+) => $name::$Var($( [<$V:lower>] ),+), )+
+                    }
+                }
+
+                #[must_use]
+                pub fn as_mut(&mut self) -> $name<$( &mut $T ),+> {
+                    match self {
+                        $( $name::$Var($( [<$V:lower>] ),+) => $name::$Var($( [<$V:lower>] ),+), )+
+                    }
+                }
+
+                #[must_use]
+                pub fn as_option_refs(&self) -> ($( Option<&$T> ),+) {
+                    self.as_ref().into()
+                }
+
+                #[must_use]
+                pub fn as_deref(&self) -> $name<$( &$T::Target ),+>
+                where
+                    $( $T: ::core::ops::Deref, )+
+                {
+                    match self {
+                        $(
+                            $name::$Var($( [<$V:lower>] ),+) => {
+                                $name::$Var($( [<$V:lower>].deref() ),+)
+                            }
+                        )+
+                    }
+                }
+
+                #[must_use]
+                pub fn all(self) -> Option<($( $T ),+)> {
+                    match self {
+                        $name::[<$( $T )+>]($( [<$T:lower>] ),+) => Some(($( [<$T:lower>] ),+)),
+                        _ => None,
+                    }
+                }
+
+                #[track_caller]
+                pub fn expect_all(self, msg: &str) -> ($( $T ),+) {
+                    self.all().expect(msg)
+                }
+
+                #[must_use]
+                pub fn count(&self) -> usize {
+                    match self {
+                        $( $name::$Var(..) => [$( stringify!($V) ),+].len(), )+
+                    }
+                }
+
+                #[must_use]
+                pub const fn present_mask(&self) -> u8 {
+                    match self {
+                        $( $name::$Var(..) => [<$Var _VARIANT_MASK>], )+
+                    }
+                }
+
+                #[must_use]
+                pub fn presence(&self) -> [<$name Presence>] {
+                    [<$name Presence>] {
+                        $( [<$T:lower>]: self.[<is_ $T:lower>](), )+
+                    }
+                }
+
+                #[must_use]
+                pub fn contains(&self, slot: $crate::Slot) -> bool {
+                    self.present_mask() & slot.mask() != 0
+                }
+
+                pub fn components_present(&self) -> impl Iterator<Item = &'static str> {
+                    let mask = self.present_mask();
+                    [$( ([<$T _MASK>], stringify!([<$T:lower>])) ),+]
+                        .into_iter()
+                        .filter(move |&(bit, _)| mask & bit != 0)
+                        .map(|(_, label)| label)
+                }
+
+                #[must_use]
+                pub fn same_variant(&self, other: &Self) -> bool {
+                    self.present_mask() == other.present_mask()
+                }
+
+                #[must_use]
+                pub fn shares_component(&self, other: &Self) -> bool
+                where
+                    $( $T: PartialEq, )+
+                {
+                    $(
+                        matches!(
+                            (self.[<$T:lower _ref>](), other.[<$T:lower _ref>]()),
+                            (Some(x), Some(y)) if x == y
+                        )
+                    )||+
+                }
+
+                #[must_use]
+                pub const fn variant_name(&self) -> &'static str {
+                    match self {
+                        $( $name::$Var(..) => stringify!($Var), )+
+                    }
+                }
+
+                #[must_use]
+                #[allow(clippy::too_many_arguments)]
+                pub fn map<$( [<F $T>], )+ $( [<$T 2>] ),+>(
+                    self,
+                    $( [<f $T:lower>]: [<F $T>] ),+
+                ) -> $name<$( [<$T 2>] ),+>
+                where
+                    $( [<F $T>]: FnOnce($T) -> [<$T 2>], )+
+                {
+                    match self {
+                        $(
+                            $name::$Var($( [<$V:lower>] ),+) => {
+                                $name::$Var($( [<f $V:lower>]([<$V:lower>]) ),+)
+                            }
+                        )+
+                    }
+                }
+
+                #[must_use]
+                #[allow(clippy::too_many_arguments)]
+                pub fn map_ref<$( [<F $T>], )+ $( [<$T 2>] ),+>(
+                    &self,
+                    $( [<f $T:lower>]: [<F $T>] ),+
+                ) -> $name<$( [<$T 2>] ),+>
+                where
+                    $( [<F $T>]: FnOnce(&$T) -> [<$T 2>], )+
+                {
+                    self.as_ref().map($( [<f $T:lower>] ),+)
+                }
+
+                #[must_use]
+                pub fn map_into<$( [<$T 2>] ),+>(self) -> $name<$( [<$T 2>] ),+>
+                where
+                    $( $T: Into<[<$T 2>]>, )+
+                {
+                    self.map($( <$T as Into<[<$T 2>]>>::into ),+)
+                }
+
+                // The error holds only the first failed component, in the variant for its slot:
+                #[allow(clippy::type_complexity)]
+                pub fn try_map_into<$( [<$T 2>] ),+>(
+                    self,
+                ) -> Result<$name<$( [<$T 2>] ),+>, $name<$( <$T as TryInto<[<$T 2>]>>::Error ),+>>
+                where
+                    $( $T: TryInto<[<$T 2>]>, )+
+                {
+                    let ($( [<$T:lower>] ),+) = self.into();
+                    Ok($name::from_present_options($(
+                        match [<$T:lower>].map(TryInto::try_into).transpose() {
+                            Ok([<$T:lower>]) => [<$T:lower>],
+                            Err(err) => return Err($name::$T(err)),
+                        }
+                    ),+))
+                }
+
+                #[must_use]
+                pub fn apply<$( [<F $T>], )+ $( [<$T 2>] ),+>(
+                    self,
+                    fns: $name<$( [<F $T>] ),+>,
+                ) -> Option<$name<$( [<$T 2>] ),+>>
+                where
+                    $( [<F $T>]: FnOnce($T) -> [<$T 2>], )+
+                {
+                    let ($( [<$T:lower>] ),+) = self.into();
+                    let ($( [<f $T:lower>] ),+) = fns.into();
+                    $name::try_from_options($(
+                        [<$T:lower>].zip([<f $T:lower>]).map(|(x, apply)| apply(x))
+                    ),+)
+                }
+
+                #[must_use]
+                #[allow(clippy::too_many_arguments)]
+                pub fn fold<R, $( [<F $T>], )+ Combine>(
+                    self,
+                    $( [<f $T:lower>]: [<F $T>], )+
+                    combine: Combine,
+                ) -> R
+                where
+                    $( [<F $T>]: FnOnce($T) -> R, )+
+                    Combine: FnMut(R, R) -> R,
+                {
+                    match self {
+                        $(
+                            $name::$Var($( [<$V:lower>] ),+) => {
+                                let [first, rest @ ..] = [$( [<f $V:lower>]([<$V:lower>]) ),+];
+                                rest.into_iter().fold(first, combine)
+                            }
+                        )+
+                    }
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn retain_present<$( [<P $T>] ),+>(
+                    self,
+                    $( [<p $T:lower>]: [<P $T>] ),+
+                ) -> Result<Self, Self>
+                where
+                    $( [<P $T>]: FnOnce(&$T) -> bool, )+
+                {
+                    let mut keep = 0;
+                    $(
+                        if self.[<$T:lower _ref>]().is_some_and([<p $T:lower>]) {
+                            keep |= [<$T _MASK>];
+                        }
+                    )+
+                    if keep == 0 {
+                        return Err(self);
+                    }
+                    let ($( [<$T:lower>] ),+) = self.into();
+                    Ok(Self::from_present_options(
+                        $( [<$T:lower>].filter(|_| keep & [<$T _MASK>] != 0) ),+
+                    ))
+                }
+
+                pub fn for_each_display<Visit>(&self, mut visit: Visit)
+                where
+                    $( $T: ::core::fmt::Display, )+
+                    Visit: FnMut(&dyn ::core::fmt::Display),
+                {
+                    $(
+                        if let Some([<$T:lower>]) = self.[<$T:lower _ref>]() {
+                            visit([<$T:lower>]);
+                        }
+                    )+
+                }
+
+                pub fn replace_with<Transform>(&mut self, f: Transform)
+                where
+                    Transform: FnOnce(Self) -> Self,
+                {
+                    $crate::replace::replace_with(self, f);
+                }
+
+                pub fn take(&mut self) -> Self
+                where
+                    Self: Default,
+                {
+                    ::core::mem::take(self)
+                }
+
+                #[cfg(feature = "std")]
+                #[must_use]
+                pub fn to_label_map(&self) -> ::std::collections::BTreeMap<&'static str, String>
+                where
+                    $( $T: ::core::fmt::Display, )+
+                {
+                    let mut map = ::std::collections::BTreeMap::new();
+                    $(
+                        if let Some([<$T:lower>]) = self.[<$T:lower _ref>]() {
+                            map.insert(stringify!([<$T:lower>]), [<$T:lower>].to_string());
+                        }
+                    )+
+                    map
+                }
+
+                #[must_use]
+                pub fn or_merge(self, other: Self) -> Self {
+                    let ($( [<$T:lower>] ),+) = self.into();
+                    let ($( [<other_ $T:lower>] ),+) = other.into();
+                    Self::from_present_options($( [<$T:lower>].or([<other_ $T:lower>]) ),+)
+                }
+
+                #[allow(clippy::too_many_arguments)]
+                pub fn merge_with<$( [<F $T>] ),+>(
+                    self,
+                    other: Self,
+                    $( [<f $T:lower>]: [<F $T>] ),+
+                ) -> Self
+                where
+                    $( [<F $T>]: FnOnce($T, $T) -> $T, )+
+                {
+                    let ($( [<$T:lower>] ),+) = self.into();
+                    let ($( [<other_ $T:lower>] ),+) = other.into();
+                    Self::from_present_options($(
+                        match ([<$T:lower>], [<other_ $T:lower>]) {
+                            (Some(x), Some(y)) => Some([<f $T:lower>](x, y)),
+                            (x, y) => x.or(y),
+                        }
+                    ),+)
+                }
+
+                pub fn xor_merge(self, other: Self) -> Result<Self, $crate::ConflictError> {
+                    if self.present_mask() & other.present_mask() != 0 {
+                        return Err($crate::ConflictError);
+                    }
+                    Ok(self.or_merge(other))
+                }
+            }
+
+            impl<'a, $( $T: ?Sized ),+> $name<$( &'a $T ),+> {
+                #[must_use]
+                pub fn cloned(self) -> $name<$( $T ),+>
+                where
+                    $( $T: Clone, )+
+                {
+                    self.map($( <$T as Clone>::clone ),+)
+                }
+
+                #[must_use]
+                pub fn copied(self) -> $name<$( $T ),+>
+                where
+                    $( $T: Copy, )+
+                {
+                    self.map($( |&[<$T:lower>]: &$T| [<$T:lower>] ),+)
+                }
+
+                #[cfg(feature = "std")]
+                #[must_use]
+                pub fn into_owned(self) -> $name<$( <$T as ToOwned>::Owned ),+>
+                where
+                    $( $T: ToOwned, )+
+                {
+                    self.map($( <$T as ToOwned>::to_owned ),+)
+                }
+            }
+
+            impl<$( $T ),+> TryFrom<($( Option<$T> ),+)> for $name<$( $T ),+> {
+                type Error = $crate::AllAbsent;
+
+                fn try_from(parts: ($( Option<$T> ),+)) -> Result<Self, Self::Error> {
+                    Self::from_parts(parts)
+                }
+            }
+
+            impl<$( $T ),+> From<($( $T ),+)> for $name<$( $T ),+> {
+                fn from(($( [<$T:lower>] ),+): ($( $T ),+)) -> Self {
+                    $name::[<$( $T )+>]($( [<$T:lower>] ),+)
+                }
+            }
+
+            impl<$( $T ),+> From<$name<$( $T ),+>> for ($( Option<$T> ),+) {
+                fn from(sp: $name<$( $T ),+>) -> ($( Option<$T> ),+) {
+                    $( let mut [<opt $T:lower>] = None; )+
+                    match sp {
+                        $(
+                            $name::$Var($( [<$V:lower>] ),+) => {
+                                $( [<opt $V:lower>] = Some([<$V:lower>]); )+
+                            }
+                        )+
+                    }
+                    ($( [<opt $T:lower>] ),+)
+                }
+            }
+
+            impl<$( $T ),+> PartialEq<($( Option<$T> ),+)> for $name<$( $T ),+>
+            where
+                $( $T: PartialEq, )+
+            {
+                fn eq(&self, other: &($( Option<$T> ),+)) -> bool {
+                    let ($( [<opt $T:lower>] ),+) = self.as_ref().into();
+                    let ($( [<other_ $T:lower>] ),+) = other;
+                    $( [<opt $T:lower>] == [<other_ $T:lower>].as_ref() )&&+
+                }
+            }
+
+            impl<$( $T ),+> ::core::fmt::Display for $name<$( $T ),+>
+            where
+                $( $T: ::core::fmt::Display, )+
+            {
+                fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        $(
+                            $name::$Var($( [<$V:lower>] ),+) => {
+                                $crate::display::write_fields(
+                                    formatter,
+                                    &[$( (stringify!($V), [<$V:lower>]) ),+],
+                                )
+                            }
+                        )+
+                    }
+                }
+            }
+
+            #[cfg(feature = "arbitrary")]
+            impl<'a, $( $T ),+> ::arbitrary::Arbitrary<'a> for $name<$( $T ),+>
+            where
+                $( $T: ::arbitrary::Arbitrary<'a>, )+
+            {
+                fn arbitrary(u: &mut ::arbitrary::Unstructured<'a>) -> ::arbitrary::Result<Self> {
+                    // Choose a non-empty subset of components in the `present_mask` layout:
+                    let mask = u.int_in_range(1..=(0 $( | [<$T _MASK>] )+))?;
+                    Ok(Self::from_present_options($(
+                        if mask & [<$T _MASK>] != 0 {
+                            Some(<$T as ::arbitrary::Arbitrary>::arbitrary(u)?)
+                        } else {
+                            None
+                        }
+                    ),+))
+                }
+            }
+
+            #[cfg(feature = "proptest")]
+            #[allow(clippy::too_many_arguments)]
+            pub fn [<$name:lower _strategy>]<$( $T, )+ $( [<S $T>] ),+>(
+                $( [<$T:lower>]: [<S $T>] ),+
+            ) -> impl ::proptest::strategy::Strategy<Value = $name<$( $T ),+>>
+            where
+                $( $T: ::core::fmt::Debug, )+
+                $( [<S $T>]: ::proptest::strategy::Strategy<Value = $T>, )+
+            {
+                use ::proptest::strategy::Strategy;
+
+                // Choose a non-empty subset of components in the `present_mask` layout:
+                let mask = 1..=(0 $( | [<$T _MASK>] )+);
+                (mask, $( [<$T:lower>] ),+).prop_map(|(mask, $( [<$T:lower>] ),+)| {
+                    $name::from_present_options($(
+                        if mask & [<$T _MASK>] != 0 {
+                            Some([<$T:lower>])
+                        } else {
+                            None
+                        }
+                    ),+)
+                })
+            }
+
+            #[cfg(feature = "serde")]
+            impl<$( $T ),+> ::serde::Serialize for $name<$( $T ),+>
+            where
+                $( $T: ::serde::Serialize, )+
+            {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: ::serde::Serializer,
+                {
+                    use ::serde::ser::SerializeMap;
+
+                    let ($( [<opt $T:lower>] ),+) = self.as_ref().into();
+                    let len = [$( [<opt $T:lower>].is_some() ),+]
+                        .into_iter()
+                        .filter(|&p| p)
+                        .count();
+                    let mut map = serializer.serialize_map(Some(len))?;
+                    $(
+                        if let Some([<$T:lower>]) = [<opt $T:lower>] {
+                            map.serialize_entry(stringify!([<$T:lower>]), [<$T:lower>])?;
+                        }
+                    )+
+                    map.end()
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de, $( $T ),+> ::serde::Deserialize<'de> for $name<$( $T ),+>
+            where
+                $( $T: ::serde::Deserialize<'de>, )+
+            {
+                fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                where
+                    De: ::serde::Deserializer<'de>,
+                {
+                    use ::core::fmt;
+                    use ::core::marker::PhantomData;
+                    use ::serde::de::{Error, MapAccess, Visitor};
+
+                    const FIELDS: &[&str] = &[$( stringify!([<$T:lower>]) ),+];
+
+                    enum Field {
+                        $( $T, )+
+                    }
+
+                    impl<'de> ::serde::Deserialize<'de> for Field {
+                        fn deserialize<De>(deserializer: De) -> Result<Self, De::Error>
+                        where
+                            De: ::serde::Deserializer<'de>,
+                        {
+                            struct FieldVisitor;
+
+                            impl<'de> Visitor<'de> for FieldVisitor {
+                                type Value = Field;
+
+                                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                                    write!(f, "one of the keys {:?}", FIELDS)
+                                }
+
+                                fn visit_str<E>(self, v: &str) -> Result<Field, E>
+                                where
+                                    E: Error,
+                                {
+                                    $(
+                                        if v == stringify!([<$T:lower>]) {
+                                            return Ok(Field::$T);
+                                        }
+                                    )+
+                                    Err(E::unknown_field(v, FIELDS))
+                                }
+
+                                fn visit_bytes<E>(self, v: &[u8]) -> Result<Field, E>
+                                where
+                                    E: Error,
+                                {
+                                    $(
+                                        if v == stringify!([<$T:lower>]).as_bytes() {
+                                            return Ok(Field::$T);
+                                        }
+                                    )+
+                                    let v = ::core::str::from_utf8(v).unwrap_or("\u{fffd}");
+                                    Err(E::unknown_field(v, FIELDS))
+                                }
+
+                                // Field indexes follow parameter order, as with derived impls:
+                                fn visit_u64<E>(self, v: u64) -> Result<Field, E>
+                                where
+                                    E: Error,
+                                {
+                                    $(
+                                        if v == Field::$T as u64 {
+                                            return Ok(Field::$T);
+                                        }
+                                    )+
+                                    Err(E::custom(format_args!(
+                                        "unknown field index `{}`, expected one of {:?}",
+                                        v, FIELDS,
+                                    )))
+                                }
+                            }
+
+                            deserializer.deserialize_identifier(FieldVisitor)
+                        }
+                    }
+
+                    struct SomeVisitor<$( $T ),+>(PhantomData<($( $T ),+)>);
+
+                    impl<'de, $( $T ),+> Visitor<'de> for SomeVisitor<$( $T ),+>
+                    where
+                        $( $T: ::serde::Deserialize<'de>, )+
+                    {
+                        type Value = $name<$( $T ),+>;
+
+                        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            write!(f, "a map with at least one of the keys {:?}", FIELDS)
+                        }
+
+                        fn visit_map<M>(self, mut map: M) -> Result<Self::Value, M::Error>
+                        where
+                            M: MapAccess<'de>,
+                        {
+                            $( let mut [<opt $T:lower>] = None; )+
+                            while let Some(field) = map.next_key()? {
+                                match field {
+                                    $(
+                                        Field::$T => {
+                                            if [<opt $T:lower>].is_some() {
+                                                return Err(M::Error::duplicate_field(
+                                                    stringify!([<$T:lower>]),
+                                                ));
+                                            }
+                                            [<opt $T:lower>] = Some(map.next_value()?);
+                                        }
+                                    )+
+                                }
+                            }
+                            $name::try_from_options($( [<opt $T:lower>] ),+)
+                                .ok_or_else(|| M::Error::custom($crate::AllAbsent))
+                        }
+                    }
+
+                    deserializer.deserialize_map(SomeVisitor(PhantomData))
+                }
+            }
+
+            #[cfg(feature = "borsh")]
+            impl<$( $T ),+> ::borsh::BorshSerialize for $name<$( $T ),+>
+            where
+                $( $T: ::borsh::BorshSerialize, )+
+            {
+                fn serialize<W>(&self, writer: &mut W) -> ::borsh::io::Result<()>
+                where
+                    W: ::borsh::io::Write,
+                {
+                    // The tag is the `present_mask`, followed by the present components in order:
+                    self.present_mask().serialize(writer)?;
+                    let ($( [<opt $T:lower>] ),+) = self.as_ref().into();
+                    $(
+                        if let Some([<$T:lower>]) = [<opt $T:lower>] {
+                            [<$T:lower>].serialize(writer)?;
+                        }
+                    )+
+                    Ok(())
+                }
+            }
+
+            #[cfg(feature = "borsh")]
+            impl<$( $T ),+> ::borsh::BorshDeserialize for $name<$( $T ),+>
+            where
+                $( $T: ::borsh::BorshDeserialize, )+
+            {
+                fn deserialize_reader<R>(reader: &mut R) -> ::borsh::io::Result<Self>
+                where
+                    R: ::borsh::io::Read,
+                {
+                    use ::borsh::io::{Error, ErrorKind};
+
+                    let mask = u8::deserialize_reader(reader)?;
+                    if mask == 0 || mask & !(0 $( | [<$T _MASK>] )+) != 0 {
+                        return Err(Error::new(ErrorKind::InvalidData, "invalid someval tag"));
+                    }
+                    Ok(Self::from_present_options($(
+                        if mask & [<$T _MASK>] != 0 {
+                            Some($T::deserialize_reader(reader)?)
+                        } else {
+                            None
+                        }
+                    ),+))
+                }
+            }
+        }
+
+        some_n!(@default $name [$( $T ),+]);
+        some_n!(@homogeneous $name [$( $T ),+]);
+        some_n!(@slots $name [$( $T ),+] [] [$( $T ),+]);
+    };
+
+    // The default populates only the first component, since the empty state is illegal:
+    (@default $name:ident [$first:ident $(, $rest:ident )*]) => {
+        impl<$first, $( $rest ),*> Default for $name<$first, $( $rest ),*>
+        where
+            $first: Default,
+        {
+            fn default() -> Self {
+                $name::$first($first::default())
+            }
+        }
+    };
+
+    // Methods for when every type parameter is the same type `T`:
+    (@homogeneous $name:ident [$( $T:ident ),+]) => {
+        ::pastey::paste! {
+            impl<T> $name<$( some_n!(@same $T T) ),+> {
+                #[must_use]
+                pub fn map_all<U, F>(self, f: F) -> $name<$( some_n!(@same $T U) ),+>
+                where
+                    F: Fn(T) -> U,
+                {
+                    self.map($( some_n!(@same $T &f) ),+)
+                }
+
+                #[must_use]
+                pub fn first(self) -> T {
+                    self.fold($( some_n!(@same $T ::core::convert::identity), )+ |first, _| first)
+                }
+
+                pub fn reduce<F>(self, f: F) -> T
+                where
+                    F: FnMut(T, T) -> T,
+                {
+                    self.fold($( some_n!(@same $T ::core::convert::identity), )+ f)
+                }
+
+                pub fn try_fold<R, E, F>(self, init: R, f: F) -> Result<R, E>
+                where
+                    F: FnMut(R, T) -> Result<R, E>,
+                {
+                    self.into_iter().try_fold(init, f)
+                }
+
+                #[must_use]
+                pub fn present_refs(
+                    &self,
+                ) -> $crate::PresentIter<&T, { <[&str]>::len(&[$( stringify!($T) ),+]) }> {
+                    self.as_ref().into_iter()
+                }
+
+                #[cfg(feature = "std")]
+                #[must_use]
+                pub fn into_present_vec(self) -> Vec<T> {
+                    self.into_iter().collect()
+                }
+            }
+
+            impl<T> IntoIterator for $name<$( some_n!(@same $T T) ),+> {
+                type Item = T;
+                type IntoIter = $crate::PresentIter<T, { <[&str]>::len(&[$( stringify!($T) ),+]) }>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    let ($( [<$T:lower>] ),+) = self.into();
+                    $crate::PresentIter::new([$( [<$T:lower>] ),+])
+                }
+            }
+        }
+    };
+
+    // Expand to the given tokens once per type parameter:
+    (@same $T:ident $( $tt:tt )+) => { $( $tt )+ };
+
+    // Generate the per-component methods for each type parameter in turn, tracking which
+    // parameters come before and after the current one:
+    (
+        @slots $name:ident [$( $T:ident ),+] [$( $before:ident ),*]
+        [$cur:ident $(, $after:ident )*]
+    ) => {
+        some_n!(@slot $name [$( $T ),+] [$( $before ),*] $cur [$( $after ),*]);
+        some_n!(@slots $name [$( $T ),+] [$( $before, )* $cur] [$( $after ),*]);
+    };
+
+    (@slots $name:ident [$( $T:ident ),+] [$( $before:ident ),*] []) => {};
+
+    (
+        @slot $name:ident [$( $T:ident ),+] [$( $before:ident ),*]
+        $cur:ident [$( $after:ident ),*]
+    ) => {
+        ::pastey::paste! {
+            // The `present_mask` bit for this component is its parameter position:
+            const [<$cur _MASK>]: u8 = 1 << <[&str]>::len(&[$( stringify!($before) ),*]);
+
+            impl<$( $T ),+> $name<$( $T ),+> {
+                #[must_use]
+                pub const fn [<new_ $cur:lower>]([<$cur:lower>]: $cur) -> Self {
+                    $name::$cur([<$cur:lower>])
+                }
+
+                #[must_use]
+                pub const fn [<from_ $cur:lower>]([<$cur:lower>]: $cur) -> Self {
+                    Self::[<new_ $cur:lower>]([<$cur:lower>])
+                }
+
+                #[must_use]
+                pub fn [<$cur:lower>](self) -> Option<$cur> {
+                    let ($( [<_ $before:lower>], )* [<opt $cur:lower>], $( [<_ $after:lower>] ),*) =
+                        self.into();
+                    [<opt $cur:lower>]
+                }
+
+                #[must_use]
+                pub fn [<into_iter_ $cur:lower>](self) -> ::core::option::IntoIter<$cur> {
+                    self.[<$cur:lower>]().into_iter()
+                }
+
+                #[must_use]
+                pub fn [<only_ $cur:lower>](self) -> Option<$cur> {
+                    match self {
+                        $name::$cur([<$cur:lower>]) => Some([<$cur:lower>]),
+                        _ => None,
+                    }
+                }
+
+                #[must_use]
+                pub fn [<$cur:lower _ref>](&self) -> Option<&$cur> {
+                    self.as_ref().[<$cur:lower>]()
+                }
+
+                #[must_use]
+                pub fn [<$cur:lower _mut>](&mut self) -> Option<&mut $cur> {
+                    self.as_mut().[<$cur:lower>]()
+                }
+
+                #[must_use]
+                pub fn [<is_ $cur:lower>](&self) -> bool {
+                    self.[<$cur:lower _ref>]().is_some()
+                }
+
+                #[track_caller]
+                pub fn [<unwrap_ $cur:lower>](self) -> $cur {
+                    match self.[<$cur:lower>]() {
+                        Some([<$cur:lower>]) => [<$cur:lower>],
+                        None => panic!(concat!(
+                            "called `",
+                            stringify!($name),
+                            "::",
+                            stringify!([<unwrap_ $cur:lower>]),
+                            "()` on a value with no `",
+                            stringify!([<$cur:lower>]),
+                            "` component",
+                        )),
+                    }
+                }
+
+                #[track_caller]
+                pub fn [<expect_ $cur:lower>](self, msg: &str) -> $cur {
+                    self.[<$cur:lower>]().expect(msg)
+                }
+
+                pub fn [<replace_ $cur:lower>](&mut self, value: $cur) -> Option<$cur> {
+                    if let Some([<$cur:lower>]) = self.[<$cur:lower _mut>]() {
+                        return Some(::core::mem::replace([<$cur:lower>], value));
+                    }
+                    $crate::replace::replace_with(self, |sv| {
+                        let ($( [<$before:lower>], )* _, $( [<$after:lower>] ),*) = sv.into();
+                        Self::from_present_options(
+                            $( [<$before:lower>], )*
+                            Some(value),
+                            $( [<$after:lower>] ),*
+                        )
+                    });
+                    None
+                }
+
+                #[must_use]
+                pub fn [<with_ $cur:lower>](mut self, value: $cur) -> Self {
+                    self.[<replace_ $cur:lower>](value);
+                    self
+                }
+
+                pub fn [<entry_ $cur:lower>](&mut self) -> &mut $cur
+                where
+                    $cur: Default,
+                {
+                    if !self.[<is_ $cur:lower>]() {
+                        self.[<replace_ $cur:lower>]($cur::default());
+                    }
+                    self.[<$cur:lower _mut>]().expect("the component was just inserted")
+                }
+
+                #[must_use]
+                pub fn [<ensure_ $cur:lower>](self, value: $cur) -> Self {
+                    if self.[<is_ $cur:lower>]() {
+                        self
+                    } else {
+                        self.[<with_ $cur:lower>](value)
+                    }
+                }
+
+                pub fn [<take_ $cur:lower>](&mut self) -> Option<$cur> {
+                    if !self.[<is_ $cur:lower>]() || self.count() == 1 {
+                        return None;
+                    }
+                    let mut taken = None;
+                    $crate::replace::replace_with(self, |sv| {
+                        let ($( [<$before:lower>], )* [<$cur:lower>], $( [<$after:lower>] ),*) =
+                            sv.into();
+                        taken = [<$cur:lower>];
+                        Self::from_present_options(
+                            $( [<$before:lower>], )*
+                            None,
+                            $( [<$after:lower>] ),*
+                        )
+                    });
+                    taken
+                }
+
+                #[must_use]
+                pub fn [<$cur:lower _map_or>]<R, [<F $cur>]>(
+                    self,
+                    default: R,
+                    [<f $cur:lower>]: [<F $cur>],
+                ) -> R
+                where
+                    [<F $cur>]: FnOnce($cur) -> R,
+                {
+                    self.[<$cur:lower>]().map_or(default, [<f $cur:lower>])
+                }
+
+                #[must_use]
+                pub fn [<$cur:lower _map_ref>]<R, [<F $cur>]>(
+                    &self,
+                    [<f $cur:lower>]: [<F $cur>],
+                ) -> Option<R>
+                where
+                    [<F $cur>]: FnOnce(&$cur) -> R,
+                {
+                    self.[<$cur:lower _ref>]().map([<f $cur:lower>])
+                }
+
+                #[must_use]
+                pub fn [<$cur:lower _map_or_else>]<R, Fallback, [<F $cur>]>(
+                    self,
+                    default: Fallback,
+                    [<f $cur:lower>]: [<F $cur>],
+                ) -> R
+                where
+                    Fallback: FnOnce() -> R,
+                    [<F $cur>]: FnOnce($cur) -> R,
+                {
+                    self.[<$cur:lower>]().map_or_else(default, [<f $cur:lower>])
+                }
+
+                pub fn [<$cur:lower _ok_or>]<Error>(self, err: Error) -> Result<$cur, Error> {
+                    self.[<$cur:lower>]().ok_or(err)
+                }
+
+                pub fn [<$cur:lower _ok_or_else>]<Error, [<F $cur>]>(
+                    self,
+                    [<f $cur:lower>]: [<F $cur>],
+                ) -> Result<$cur, Error>
+                where
+                    [<F $cur>]: FnOnce() -> Error,
+                {
+                    self.[<$cur:lower>]().ok_or_else([<f $cur:lower>])
+                }
+
+                #[must_use]
+                pub fn [<filter_ $cur:lower>]<P>(self, predicate: P) -> Option<Self>
+                where
+                    P: FnOnce(&$cur) -> bool,
+                {
+                    let ($( [<$before:lower>], )* [<$cur:lower>], $( [<$after:lower>] ),*) =
+                        self.into();
+                    Self::try_from_options(
+                        $( [<$before:lower>], )*
+                        [<$cur:lower>].filter(predicate),
+                        $( [<$after:lower>] ),*
+                    )
+                }
+
+                pub fn [<inspect_ $cur:lower>]<[<F $cur>]>(
+                    self,
+                    [<f $cur:lower>]: [<F $cur>],
+                ) -> Self
+                where
+                    [<F $cur>]: FnOnce(&$cur),
+                {
+                    if let Some([<$cur:lower>]) = self.[<$cur:lower _ref>]() {
+                        [<f $cur:lower>]([<$cur:lower>]);
+                    }
+                    self
+                }
+
+                #[must_use]
+                pub fn [<$cur:lower _or>](self, default: $cur) -> $cur {
+                    self.[<$cur:lower>]().unwrap_or(default)
+                }
+
+                #[must_use]
+                pub fn [<$cur:lower _or_else>]<[<F $cur>]>(
+                    self,
+                    [<f $cur:lower>]: [<F $cur>],
+                ) -> $cur
+                where
+                    [<F $cur>]: FnOnce() -> $cur,
+                {
+                    self.[<$cur:lower>]().unwrap_or_else([<f $cur:lower>])
+                }
+
+                #[must_use]
+                pub fn [<map_ $cur:lower>]<[<F $cur>], [<$cur 2>]>(
+                    self,
+                    [<f $cur:lower>]: [<F $cur>],
+                ) -> $name<$( $before, )* [<$cur 2>] $(, $after )*>
+                where
+                    [<F $cur>]: FnOnce($cur) -> [<$cur 2>],
+                {
+                    self.map(
+                        $( ::core::convert::identity::<$before>, )*
+                        [<f $cur:lower>],
+                        $( ::core::convert::identity::<$after> ),*
+                    )
+                }
+
+                pub fn [<try_map_ $cur:lower>]<[<$cur 2>], Error, [<F $cur>]>(
+                    self,
+                    [<f $cur:lower>]: [<F $cur>],
+                ) -> Result<$name<$( $before, )* [<$cur 2>] $(, $after )*>, Error>
+                where
+                    [<F $cur>]: FnOnce($cur) -> Result<[<$cur 2>], Error>,
+                {
+                    let ($( [<$before:lower>], )* [<$cur:lower>], $( [<$after:lower>] ),*) =
+                        self.into();
+                    Ok($name::from_present_options(
+                        $( [<$before:lower>], )*
+                        [<$cur:lower>].map([<f $cur:lower>]).transpose()?,
+                        $( [<$after:lower>] ),*
+                    ))
+                }
+
+                #[must_use]
+                pub fn [<try_map_ $cur:lower _or>]<[<$cur 2>], [<F $cur>]>(
+                    self,
+                    default: [<$cur 2>],
+                    [<f $cur:lower>]: [<F $cur>],
+                ) -> $name<$( $before, )* [<$cur 2>] $(, $after )*>
+                where
+                    [<F $cur>]: FnOnce($cur) -> Option<[<$cur 2>]>,
+                {
+                    self.[<map_ $cur:lower>](|[<$cur:lower>]| {
+                        [<f $cur:lower>]([<$cur:lower>]).unwrap_or(default)
+                    })
+                }
+            }
+        }
+    };
+}
+
+/// Construct a "someval" by naming its present components
+///
+/// Components are given as `name = value` in alphabetical order, and the variant is chosen from
+/// the names given:
+///
+/// ```
+/// use someval::{some, Some2, Some3};
+///
+/// let nid: Some2<u64, String> = some![a = 42, b = "x".to_string()];
+/// assert_eq!(nid, Some2::AB(42, "x".to_string()));
+///
+/// let triple: Some3<i64, &str, bool> = some![a = 42, c = false];
+/// assert_eq!(triple, Some3::AC(42, false));
+/// ```
+///
+/// The type is the smallest "someval" which includes the last component, so `some![b = x]` is a
+/// [Some2](crate::Some2) and `some![c = x]` is a [Some3](crate::Some3). A single `a` component is
+/// a [Some2](crate::Some2). A specific type can be given before the components:
+///
+/// ```
+/// use someval::{some, Some3};
+///
+/// let triple: Some3<i64, &str, bool> = some![Some3; a = 42];
+/// assert_eq!(triple, Some3::A(42));
+/// ```
+///
+/// So `some![a = 42, c = false]` expands to `someval::Some3::AC(42, false)` and
+/// `some![Some3; a = 42]` expands to `someval::Some3::A(42)`.
+///
+/// At least one component is required:
+///
+/// ```compile_fail
+/// use someval::{some, Some2};
+///
+/// let nid: Some2<u64, String> = some![];
+/// ```
+///
+/// Components out of alphabetical order do not name a variant:
+///
+/// ```compile_fail
+/// use someval::{some, Some2};
+///
+/// let nid: Some2<u64, String> = some![b = "x".to_string(), a = 42];
+/// ```
+#[macro_export]
+macro_rules! some {
+    () => {
+        compile_error!("`some!` requires at least one component, e.g. `some![a = 42]`")
+    };
+
+    ( $ty:ident; $( $field:ident = $value:expr ),+ $(,)? ) => {
+        $crate::__paste::paste! {
+            $crate::$ty::[<$( $field:upper )+>]($( $value ),+)
+        }
+    };
+
+    ( $( $field:ident = $value:expr ),+ $(,)? ) => {
+        $crate::some!(@infer [$( $field )+] $( $field = $value ),+)
+    };
+
+    // Find the last component name to select the type:
+    (@infer [$first:ident $( $rest:ident )+] $( $tail:tt )*) => {
+        $crate::some!(@infer [$( $rest )+] $( $tail )*)
+    };
+    (@infer [a] $( $tail:tt )*) => { $crate::some!(Some2; $( $tail )*) };
+    (@infer [b] $( $tail:tt )*) => { $crate::some!(Some2; $( $tail )*) };
+    (@infer [c] $( $tail:tt )*) => { $crate::some!(Some3; $( $tail )*) };
+    (@infer [d] $( $tail:tt )*) => { $crate::some!(Some4; $( $tail )*) };
+    (@infer [e] $( $tail:tt )*) => { $crate::some!(Some5; $( $tail )*) };
+    (@infer [f] $( $tail:tt )*) => { $crate::some!(Some6; $( $tail )*) };
+    (@infer [g] $( $tail:tt )*) => { $crate::some!(Some7; $( $tail )*) };
+    (@infer [h] $( $tail:tt )*) => { $crate::some!(Some8; $( $tail )*) };
+}
+
+/// Run a block for each present component of a "someval", regardless of its variant
+///
+/// Each arm names a component in lowercase, binds a reference to its payload, and runs its block
+/// if that component is present. Arms run in the order given, so several may run for one value:
+///
+/// ```
+/// use someval::{match_present, Some3};
+///
+/// let triple: Some3<i64, &str, bool> = Some3::AC(42, true);
+///
+/// let mut seen = vec![];
+/// match_present!(triple,
+///     a(id) => { seen.push(format!("id {}", id)); },
+///     b(name) => { seen.push(format!("name {}", name)); },
+///     c(flag) => { seen.push(format!("flag {}", flag)); },
+/// );
+/// assert_eq!(seen, vec!["id 42", "flag true"]);
+/// # let mut seen = vec![];
+/// # match_present!(Some3::<i64, &str, bool>::ABC(1, "x", false),
+/// #     c(flag) => { seen.push(flag.to_string()); },
+/// #     a(id) => { seen.push(id.to_string()); },
+/// # );
+/// # assert_eq!(seen, vec!["false", "1"]);
+/// ```
+///
+/// Absent components may be omitted, so only the components of interest need arms. The value is
+/// borrowed, not consumed, and `match_present!(triple, a(id) => { … })` expands to:
+///
+/// ```text
+/// {
+///     let value = &triple;
+///     if let Some(id) = value.a_ref() { … }
+/// }
+/// ```
+///
+/// A component name which the type lacks is an error:
+///
+/// ```compile_fail
+/// use someval::{match_present, Some2};
+///
+/// let nid: Some2<u64, String> = Some2::A(42);
+/// match_present!(nid, c(x) => {});
+/// ```
+#[macro_export]
+macro_rules! match_present {
+    ( $value:expr, $( $slot:ident ( $bind:pat ) => $body:block ),+ $(,)? ) => {
+        $crate::__paste::paste! {
+            {
+                let value = &$value;
+                $(
+                    if let Some($bind) = value.[<$slot _ref>]() $body
+                )+
+            }
+        }
+    };
+}
+
+/// Assert that a component of a "someval" is present, in debug builds only
+///
+/// Like [debug_assert], the check is compiled out unless `debug_assertions` are enabled, so the
+/// value is not even evaluated in release builds. The value is borrowed, not consumed:
+///
+/// ```
+/// use someval::{assert_present, Some3};
+///
+/// let triple: Some3<i64, &str, bool> = Some3::AC(42, true);
+/// assert_present!(triple, a);
+/// assert_present!(triple, c);
+/// assert_eq!(triple.a(), Some(42));
+/// ```
+///
+/// Otherwise it panics, naming the absent component and the actual variant:
+///
+/// ```should_panic
+/// use someval::{assert_present, Some3};
+///
+/// let triple: Some3<i64, &str, bool> = Some3::AC(42, true);
+/// // Panics with "component `b` of `triple` is absent (variant AC)":
+/// assert_present!(triple, b);
+/// ```
+///
+/// A component name which the type lacks is an error:
+///
+/// ```compile_fail
+/// use someval::{assert_present, Some2};
+///
+/// let nid: Some2<u64, String> = Some2::A(42);
+/// assert_present!(nid, c);
+/// ```
+#[macro_export]
+macro_rules! assert_present {
+    ( $value:expr, $slot:ident $(,)? ) => {
+        $crate::__paste::paste! {
+            if cfg!(debug_assertions) {
+                let value = &$value;
+                if !value.[<is_ $slot>]() {
+                    panic!(
+                        "component `{}` of `{}` is absent (variant {})",
+                        stringify!($slot),
+                        stringify!($value),
+                        value.variant_name(),
+                    );
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,26 @@
+use core::{mem, ptr};
+
+// Replace `*dest` with `f(*dest)`, aborting the process if `f` panics, since `*dest` would
+// otherwise be left moved-out during unwinding:
+pub(crate) fn replace_with<T, F>(dest: &mut T, f: F)
+where
+    F: FnOnce(T) -> T,
+{
+    struct AbortOnUnwind;
+
+    impl Drop for AbortOnUnwind {
+        fn drop(&mut self) {
+            // Panicking while already unwinding aborts:
+            panic!("panic in a someval replacement function");
+        }
+    }
+
+    let guard = AbortOnUnwind;
+    // SAFETY: `dest` is written back before returning, and if `f` unwinds, `guard` aborts before
+    // the moved-out `*dest` can be observed or dropped again.
+    unsafe {
+        let old = ptr::read(dest);
+        ptr::write(dest, f(old));
+    }
+    mem::forget(guard);
+}
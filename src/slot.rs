@@ -0,0 +1,21 @@
+/// A component position within a "someval", shared by all arities
+///
+/// `Slot::A` refers to the first type parameter, `Slot::B` to the second, etc…
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Slot {
+    A,
+    B,
+    C,
+    D,
+    E,
+    F,
+    G,
+    H,
+}
+
+impl Slot {
+    // The bit for this slot within a `present_mask`:
+    pub(crate) const fn mask(self) -> u8 {
+        1 << self as u8
+    }
+}
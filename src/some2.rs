@@ -1,60 +1,148 @@
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum Some2<A, B> {
-    A(A),
-    B(B),
-    AB(A, B),
+use crate::{Some3, Some4};
+
+some_n! {
+    Some2<A, B> {
+        A(A),
+        B(B),
+        AB(A, B),
+    }
 }
-use Some2::*;
 
 impl<A, B> Some2<A, B> {
-    pub fn try_from_options(a: Option<A>, b: Option<B>) -> Option<Self> {
-        match (a, b) {
-            (None, None) => None,
-            (Some(a), None) => Some(A(a)),
-            (None, Some(b)) => Some(B(b)),
-            (Some(a), Some(b)) => Some(AB(a, b)),
+    #[must_use]
+    pub fn swap(self) -> Some2<B, A> {
+        match self {
+            Some2::A(a) => Some2::B(a),
+            Some2::B(b) => Some2::A(b),
+            Some2::AB(a, b) => Some2::AB(b, a),
+        }
+    }
+
+    #[must_use]
+    pub fn zip<C, D>(self, other: Some2<C, D>) -> Some4<A, B, C, D> {
+        let (a, b) = self.into();
+        let (c, d) = other.into();
+        Some4::from_present_options(a, b, c, d)
+    }
+
+    #[must_use]
+    pub fn widen_a<X>(self) -> Some3<X, A, B> {
+        let (a, b) = self.into();
+        Some3::from_present_options(None, a, b)
+    }
+
+    #[must_use]
+    pub fn widen_b<X>(self) -> Some3<A, X, B> {
+        let (a, b) = self.into();
+        Some3::from_present_options(a, None, b)
+    }
+
+    #[must_use]
+    pub fn widen_c<X>(self) -> Some3<A, B, X> {
+        self.into()
+    }
+
+    pub fn into_result(self) -> Result<A, B> {
+        match self {
+            Some2::A(a) | Some2::AB(a, _) => Ok(a),
+            Some2::B(b) => Err(b),
+        }
+    }
+
+    #[cfg(feature = "either")]
+    pub fn try_into_either(self) -> Result<either::Either<A, B>, Self> {
+        match self {
+            Some2::A(a) => Ok(either::Either::Left(a)),
+            Some2::B(b) => Ok(either::Either::Right(b)),
+            other => Err(other),
         }
     }
 
-    pub fn as_ref(&self) -> Some2<&A, &B> {
+    pub fn into_result_map_err<E, F>(self, f: F) -> Result<A, E>
+    where
+        F: FnOnce(B) -> E,
+    {
+        self.into_result().map_err(f)
+    }
+}
+
+impl<A, B> Some2<Some2<A, B>, Some2<A, B>> {
+    #[must_use]
+    pub fn flatten(self) -> Some2<A, B> {
         match self {
-            A(a) => A(a),
-            B(b) => B(b),
-            AB(a, b) => AB(a, b),
+            Some2::A(inner) | Some2::B(inner) => inner,
+            Some2::AB(left, right) => left.or_merge(right),
         }
     }
+}
 
-    pub fn a(self) -> Option<A> {
-        let (opta, _) = self.into();
-        opta
+impl<T> Some2<T, T>
+where
+    T: PartialEq,
+{
+    #[must_use]
+    pub fn canonicalize(self) -> Self {
+        match self {
+            Some2::AB(a, b) if a == b => Some2::A(a),
+            other => other,
+        }
     }
+}
 
-    pub fn b(self) -> Option<B> {
-        let (_, optb) = self.into();
-        optb
+impl<A, B, C, D> Some2<(A, C), (B, D)> {
+    #[must_use]
+    pub fn unzip(self) -> (Some2<A, B>, Some2<C, D>) {
+        match self {
+            Some2::A((a, c)) => (Some2::A(a), Some2::A(c)),
+            Some2::B((b, d)) => (Some2::B(b), Some2::B(d)),
+            Some2::AB((a, c), (b, d)) => (Some2::AB(a, b), Some2::AB(c, d)),
+        }
     }
 }
 
-impl<A, B> TryFrom<(Option<A>, Option<B>)> for Some2<A, B> {
-    type Error = &'static str;
+/// Combine two options into a [Some2], or `None` if both are absent
+#[must_use]
+pub fn combine2<A, B>(a: Option<A>, b: Option<B>) -> Option<Some2<A, B>> {
+    Some2::try_from_options(a, b)
+}
+
+/// The number of values of each [Some2] variant, from [partition_by_presence]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct PartitionStats {
+    pub a: usize,
+    pub b: usize,
+    pub ab: usize,
+}
 
-    fn try_from((opta, optb): (Option<A>, Option<B>)) -> Result<Self, Self::Error> {
-        Self::try_from_options(opta, optb).ok_or("no value of any accepted type present")
+/// Count how many values are of each [Some2] variant, as [`PartitionStats`]
+#[must_use]
+pub fn partition_by_presence<A, B, I>(values: I) -> PartitionStats
+where
+    I: IntoIterator<Item = Some2<A, B>>,
+{
+    let mut stats = PartitionStats::default();
+    for value in values {
+        match value {
+            Some2::A(_) => stats.a += 1,
+            Some2::B(_) => stats.b += 1,
+            Some2::AB(..) => stats.ab += 1,
+        }
     }
+    stats
 }
 
-impl<A, B> From<(A, B)> for Some2<A, B> {
-    fn from((a, b): (A, B)) -> Self {
-        AB(a, b)
+impl<A, B> From<Some2<A, B>> for Result<A, B> {
+    fn from(sp: Some2<A, B>) -> Self {
+        sp.into_result()
     }
 }
 
-impl<A, B> From<Some2<A, B>> for (Option<A>, Option<B>) {
-    fn from(sp: Some2<A, B>) -> (Option<A>, Option<B>) {
+impl<A, B, C> From<Some2<A, B>> for Some3<A, B, C> {
+    fn from(sp: Some2<A, B>) -> Self {
         match sp {
-            A(a) => (Some(a), None),
-            B(b) => (None, Some(b)),
-            AB(a, b) => (Some(a), Some(b)),
+            Some2::A(a) => Some3::A(a),
+            Some2::B(b) => Some3::B(b),
+            Some2::AB(a, b) => Some3::AB(a, b),
         }
     }
 }
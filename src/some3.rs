@@ -1,83 +1,79 @@
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
-pub enum Some3<A, B, C> {
-    A(A),
-    B(B),
-    C(C),
-    AB(A, B),
-    AC(A, C),
-    BC(B, C),
-    ABC(A, B, C),
+use crate::Some2;
+
+some_n! {
+    Some3<A, B, C> {
+        A(A),
+        B(B),
+        C(C),
+        AB(A, B),
+        AC(A, C),
+        BC(B, C),
+        ABC(A, B, C),
+    }
 }
-use Some3::*;
 
 impl<A, B, C> Some3<A, B, C> {
-    pub fn try_from_options(a: Option<A>, b: Option<B>, c: Option<C>) -> Option<Self> {
-        match (a, b, c) {
-            (None, None, None) => None,
-            (Some(a), None, None) => Some(A(a)),
-            (None, Some(b), None) => Some(B(b)),
-            (None, None, Some(c)) => Some(C(c)),
-            (Some(a), Some(b), None) => Some(AB(a, b)),
-            (Some(a), None, Some(c)) => Some(AC(a, c)),
-            (None, Some(b), Some(c)) => Some(BC(b, c)),
-            (Some(a), Some(b), Some(c)) => Some(ABC(a, b, c)),
+    #[must_use]
+    pub fn rotate(self) -> Some3<B, C, A> {
+        match self {
+            Some3::A(a) => Some3::C(a),
+            Some3::B(b) => Some3::A(b),
+            Some3::C(c) => Some3::B(c),
+            Some3::AB(a, b) => Some3::AC(b, a),
+            Some3::AC(a, c) => Some3::BC(c, a),
+            Some3::BC(b, c) => Some3::AB(b, c),
+            Some3::ABC(a, b, c) => Some3::ABC(b, c, a),
         }
     }
 
-    pub fn as_ref(&self) -> Some3<&A, &B, &C> {
+    pub fn try_into_some2(self) -> Result<Some2<A, B>, Self> {
         match self {
-            A(a) => A(a),
-            B(b) => B(b),
-            C(c) => C(c),
-            AB(a, b) => AB(a, b),
-            AC(a, c) => AC(a, c),
-            BC(b, c) => BC(b, c),
-            ABC(a, b, c) => ABC(a, b, c),
+            Some3::A(a) => Ok(Some2::A(a)),
+            Some3::B(b) => Ok(Some2::B(b)),
+            Some3::AB(a, b) => Ok(Some2::AB(a, b)),
+            other => Err(other),
         }
     }
 
-    pub fn a(self) -> Option<A> {
-        let (opta, _, _) = self.into();
-        opta
+    #[must_use]
+    pub fn split_a(self) -> (Option<A>, Option<Some2<B, C>>) {
+        let (a, b, c) = self.into();
+        (a, Some2::try_from_options(b, c))
     }
 
-    pub fn b(self) -> Option<B> {
-        let (_, optb, _) = self.into();
-        optb
+    #[must_use]
+    pub fn split_b(self) -> (Option<B>, Option<Some2<A, C>>) {
+        let (a, b, c) = self.into();
+        (b, Some2::try_from_options(a, c))
     }
 
-    pub fn c(self) -> Option<C> {
-        let (_, _, optc) = self.into();
-        optc
+    #[must_use]
+    pub fn split_c(self) -> (Option<C>, Option<Some2<A, B>>) {
+        let (a, b, c) = self.into();
+        (c, Some2::try_from_options(a, b))
     }
-}
 
-impl<A, B, C> TryFrom<(Option<A>, Option<B>, Option<C>)> for Some3<A, B, C> {
-    type Error = &'static str;
+    #[must_use]
+    pub fn without_a(self) -> Option<Some2<B, C>> {
+        let (_, b, c) = self.into();
+        Some2::try_from_options(b, c)
+    }
 
-    fn try_from(
-        (opta, optb, optc): (Option<A>, Option<B>, Option<C>),
-    ) -> Result<Self, Self::Error> {
-        Self::try_from_options(opta, optb, optc).ok_or("no value of any accepted type present")
+    #[must_use]
+    pub fn without_b(self) -> Option<Some2<A, C>> {
+        let (a, _, c) = self.into();
+        Some2::try_from_options(a, c)
     }
-}
 
-impl<A, B, C> From<(A, B, C)> for Some3<A, B, C> {
-    fn from((a, b, c): (A, B, C)) -> Self {
-        ABC(a, b, c)
+    #[must_use]
+    pub fn without_c(self) -> Option<Some2<A, B>> {
+        let (a, b, _) = self.into();
+        Some2::try_from_options(a, b)
     }
 }
 
-impl<A, B, C> From<Some3<A, B, C>> for (Option<A>, Option<B>, Option<C>) {
-    fn from(sp: Some3<A, B, C>) -> (Option<A>, Option<B>, Option<C>) {
-        match sp {
-            A(a) => (Some(a), None, None),
-            B(b) => (None, Some(b), None),
-            C(c) => (None, None, Some(c)),
-            AB(a, b) => (Some(a), Some(b), None),
-            AC(a, c) => (Some(a), None, Some(c)),
-            BC(b, c) => (None, Some(b), Some(c)),
-            ABC(a, b, c) => (Some(a), Some(b), Some(c)),
-        }
-    }
+/// Combine three options into a [Some3], or `None` if all are absent
+#[must_use]
+pub fn combine3<A, B, C>(a: Option<A>, b: Option<B>, c: Option<C>) -> Option<Some3<A, B, C>> {
+    Some3::try_from_options(a, b, c)
 }
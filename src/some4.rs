@@ -0,0 +1,19 @@
+some_n! {
+    Some4<A, B, C, D> {
+        A(A),
+        B(B),
+        C(C),
+        D(D),
+        AB(A, B),
+        AC(A, C),
+        AD(A, D),
+        BC(B, C),
+        BD(B, D),
+        CD(C, D),
+        ABC(A, B, C),
+        ABD(A, B, D),
+        ACD(A, C, D),
+        BCD(B, C, D),
+        ABCD(A, B, C, D),
+    }
+}
@@ -0,0 +1,35 @@
+some_n! {
+    Some5<A, B, C, D, E> {
+        A(A),
+        B(B),
+        C(C),
+        D(D),
+        E(E),
+        AB(A, B),
+        AC(A, C),
+        AD(A, D),
+        AE(A, E),
+        BC(B, C),
+        BD(B, D),
+        BE(B, E),
+        CD(C, D),
+        CE(C, E),
+        DE(D, E),
+        ABC(A, B, C),
+        ABD(A, B, D),
+        ABE(A, B, E),
+        ACD(A, C, D),
+        ACE(A, C, E),
+        ADE(A, D, E),
+        BCD(B, C, D),
+        BCE(B, C, E),
+        BDE(B, D, E),
+        CDE(C, D, E),
+        ABCD(A, B, C, D),
+        ABCE(A, B, C, E),
+        ABDE(A, B, D, E),
+        ACDE(A, C, D, E),
+        BCDE(B, C, D, E),
+        ABCDE(A, B, C, D, E),
+    }
+}
@@ -0,0 +1,67 @@
+some_n! {
+    Some6<A, B, C, D, E, F> {
+        A(A),
+        B(B),
+        C(C),
+        D(D),
+        E(E),
+        F(F),
+        AB(A, B),
+        AC(A, C),
+        AD(A, D),
+        AE(A, E),
+        AF(A, F),
+        BC(B, C),
+        BD(B, D),
+        BE(B, E),
+        BF(B, F),
+        CD(C, D),
+        CE(C, E),
+        CF(C, F),
+        DE(D, E),
+        DF(D, F),
+        EF(E, F),
+        ABC(A, B, C),
+        ABD(A, B, D),
+        ABE(A, B, E),
+        ABF(A, B, F),
+        ACD(A, C, D),
+        ACE(A, C, E),
+        ACF(A, C, F),
+        ADE(A, D, E),
+        ADF(A, D, F),
+        AEF(A, E, F),
+        BCD(B, C, D),
+        BCE(B, C, E),
+        BCF(B, C, F),
+        BDE(B, D, E),
+        BDF(B, D, F),
+        BEF(B, E, F),
+        CDE(C, D, E),
+        CDF(C, D, F),
+        CEF(C, E, F),
+        DEF(D, E, F),
+        ABCD(A, B, C, D),
+        ABCE(A, B, C, E),
+        ABCF(A, B, C, F),
+        ABDE(A, B, D, E),
+        ABDF(A, B, D, F),
+        ABEF(A, B, E, F),
+        ACDE(A, C, D, E),
+        ACDF(A, C, D, F),
+        ACEF(A, C, E, F),
+        ADEF(A, D, E, F),
+        BCDE(B, C, D, E),
+        BCDF(B, C, D, F),
+        BCEF(B, C, E, F),
+        BDEF(B, D, E, F),
+        CDEF(C, D, E, F),
+        ABCDE(A, B, C, D, E),
+        ABCDF(A, B, C, D, F),
+        ABCEF(A, B, C, E, F),
+        ABDEF(A, B, D, E, F),
+        ACDEF(A, C, D, E, F),
+        BCDEF(B, C, D, E, F),
+        ABCDEF(A, B, C, D, E, F),
+    }
+}
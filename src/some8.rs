@@ -0,0 +1,259 @@
+some_n! {
+    Some8<A, B, C, D, E, F, G, H> {
+        A(A),
+        B(B),
+        C(C),
+        D(D),
+        E(E),
+        F(F),
+        G(G),
+        H(H),
+        AB(A, B),
+        AC(A, C),
+        AD(A, D),
+        AE(A, E),
+        AF(A, F),
+        AG(A, G),
+        AH(A, H),
+        BC(B, C),
+        BD(B, D),
+        BE(B, E),
+        BF(B, F),
+        BG(B, G),
+        BH(B, H),
+        CD(C, D),
+        CE(C, E),
+        CF(C, F),
+        CG(C, G),
+        CH(C, H),
+        DE(D, E),
+        DF(D, F),
+        DG(D, G),
+        DH(D, H),
+        EF(E, F),
+        EG(E, G),
+        EH(E, H),
+        FG(F, G),
+        FH(F, H),
+        GH(G, H),
+        ABC(A, B, C),
+        ABD(A, B, D),
+        ABE(A, B, E),
+        ABF(A, B, F),
+        ABG(A, B, G),
+        ABH(A, B, H),
+        ACD(A, C, D),
+        ACE(A, C, E),
+        ACF(A, C, F),
+        ACG(A, C, G),
+        ACH(A, C, H),
+        ADE(A, D, E),
+        ADF(A, D, F),
+        ADG(A, D, G),
+        ADH(A, D, H),
+        AEF(A, E, F),
+        AEG(A, E, G),
+        AEH(A, E, H),
+        AFG(A, F, G),
+        AFH(A, F, H),
+        AGH(A, G, H),
+        BCD(B, C, D),
+        BCE(B, C, E),
+        BCF(B, C, F),
+        BCG(B, C, G),
+        BCH(B, C, H),
+        BDE(B, D, E),
+        BDF(B, D, F),
+        BDG(B, D, G),
+        BDH(B, D, H),
+        BEF(B, E, F),
+        BEG(B, E, G),
+        BEH(B, E, H),
+        BFG(B, F, G),
+        BFH(B, F, H),
+        BGH(B, G, H),
+        CDE(C, D, E),
+        CDF(C, D, F),
+        CDG(C, D, G),
+        CDH(C, D, H),
+        CEF(C, E, F),
+        CEG(C, E, G),
+        CEH(C, E, H),
+        CFG(C, F, G),
+        CFH(C, F, H),
+        CGH(C, G, H),
+        DEF(D, E, F),
+        DEG(D, E, G),
+        DEH(D, E, H),
+        DFG(D, F, G),
+        DFH(D, F, H),
+        DGH(D, G, H),
+        EFG(E, F, G),
+        EFH(E, F, H),
+        EGH(E, G, H),
+        FGH(F, G, H),
+        ABCD(A, B, C, D),
+        ABCE(A, B, C, E),
+        ABCF(A, B, C, F),
+        ABCG(A, B, C, G),
+        ABCH(A, B, C, H),
+        ABDE(A, B, D, E),
+        ABDF(A, B, D, F),
+        ABDG(A, B, D, G),
+        ABDH(A, B, D, H),
+        ABEF(A, B, E, F),
+        ABEG(A, B, E, G),
+        ABEH(A, B, E, H),
+        ABFG(A, B, F, G),
+        ABFH(A, B, F, H),
+        ABGH(A, B, G, H),
+        ACDE(A, C, D, E),
+        ACDF(A, C, D, F),
+        ACDG(A, C, D, G),
+        ACDH(A, C, D, H),
+        ACEF(A, C, E, F),
+        ACEG(A, C, E, G),
+        ACEH(A, C, E, H),
+        ACFG(A, C, F, G),
+        ACFH(A, C, F, H),
+        ACGH(A, C, G, H),
+        ADEF(A, D, E, F),
+        ADEG(A, D, E, G),
+        ADEH(A, D, E, H),
+        ADFG(A, D, F, G),
+        ADFH(A, D, F, H),
+        ADGH(A, D, G, H),
+        AEFG(A, E, F, G),
+        AEFH(A, E, F, H),
+        AEGH(A, E, G, H),
+        AFGH(A, F, G, H),
+        BCDE(B, C, D, E),
+        BCDF(B, C, D, F),
+        BCDG(B, C, D, G),
+        BCDH(B, C, D, H),
+        BCEF(B, C, E, F),
+        BCEG(B, C, E, G),
+        BCEH(B, C, E, H),
+        BCFG(B, C, F, G),
+        BCFH(B, C, F, H),
+        BCGH(B, C, G, H),
+        BDEF(B, D, E, F),
+        BDEG(B, D, E, G),
+        BDEH(B, D, E, H),
+        BDFG(B, D, F, G),
+        BDFH(B, D, F, H),
+        BDGH(B, D, G, H),
+        BEFG(B, E, F, G),
+        BEFH(B, E, F, H),
+        BEGH(B, E, G, H),
+        BFGH(B, F, G, H),
+        CDEF(C, D, E, F),
+        CDEG(C, D, E, G),
+        CDEH(C, D, E, H),
+        CDFG(C, D, F, G),
+        CDFH(C, D, F, H),
+        CDGH(C, D, G, H),
+        CEFG(C, E, F, G),
+        CEFH(C, E, F, H),
+        CEGH(C, E, G, H),
+        CFGH(C, F, G, H),
+        DEFG(D, E, F, G),
+        DEFH(D, E, F, H),
+        DEGH(D, E, G, H),
+        DFGH(D, F, G, H),
+        EFGH(E, F, G, H),
+        ABCDE(A, B, C, D, E),
+        ABCDF(A, B, C, D, F),
+        ABCDG(A, B, C, D, G),
+        ABCDH(A, B, C, D, H),
+        ABCEF(A, B, C, E, F),
+        ABCEG(A, B, C, E, G),
+        ABCEH(A, B, C, E, H),
+        ABCFG(A, B, C, F, G),
+        ABCFH(A, B, C, F, H),
+        ABCGH(A, B, C, G, H),
+        ABDEF(A, B, D, E, F),
+        ABDEG(A, B, D, E, G),
+        ABDEH(A, B, D, E, H),
+        ABDFG(A, B, D, F, G),
+        ABDFH(A, B, D, F, H),
+        ABDGH(A, B, D, G, H),
+        ABEFG(A, B, E, F, G),
+        ABEFH(A, B, E, F, H),
+        ABEGH(A, B, E, G, H),
+        ABFGH(A, B, F, G, H),
+        ACDEF(A, C, D, E, F),
+        ACDEG(A, C, D, E, G),
+        ACDEH(A, C, D, E, H),
+        ACDFG(A, C, D, F, G),
+        ACDFH(A, C, D, F, H),
+        ACDGH(A, C, D, G, H),
+        ACEFG(A, C, E, F, G),
+        ACEFH(A, C, E, F, H),
+        ACEGH(A, C, E, G, H),
+        ACFGH(A, C, F, G, H),
+        ADEFG(A, D, E, F, G),
+        ADEFH(A, D, E, F, H),
+        ADEGH(A, D, E, G, H),
+        ADFGH(A, D, F, G, H),
+        AEFGH(A, E, F, G, H),
+        BCDEF(B, C, D, E, F),
+        BCDEG(B, C, D, E, G),
+        BCDEH(B, C, D, E, H),
+        BCDFG(B, C, D, F, G),
+        BCDFH(B, C, D, F, H),
+        BCDGH(B, C, D, G, H),
+        BCEFG(B, C, E, F, G),
+        BCEFH(B, C, E, F, H),
+        BCEGH(B, C, E, G, H),
+        BCFGH(B, C, F, G, H),
+        BDEFG(B, D, E, F, G),
+        BDEFH(B, D, E, F, H),
+        BDEGH(B, D, E, G, H),
+        BDFGH(B, D, F, G, H),
+        BEFGH(B, E, F, G, H),
+        CDEFG(C, D, E, F, G),
+        CDEFH(C, D, E, F, H),
+        CDEGH(C, D, E, G, H),
+        CDFGH(C, D, F, G, H),
+        CEFGH(C, E, F, G, H),
+        DEFGH(D, E, F, G, H),
+        ABCDEF(A, B, C, D, E, F),
+        ABCDEG(A, B, C, D, E, G),
+        ABCDEH(A, B, C, D, E, H),
+        ABCDFG(A, B, C, D, F, G),
+        ABCDFH(A, B, C, D, F, H),
+        ABCDGH(A, B, C, D, G, H),
+        ABCEFG(A, B, C, E, F, G),
+        ABCEFH(A, B, C, E, F, H),
+        ABCEGH(A, B, C, E, G, H),
+        ABCFGH(A, B, C, F, G, H),
+        ABDEFG(A, B, D, E, F, G),
+        ABDEFH(A, B, D, E, F, H),
+        ABDEGH(A, B, D, E, G, H),
+        ABDFGH(A, B, D, F, G, H),
+        ABEFGH(A, B, E, F, G, H),
+        ACDEFG(A, C, D, E, F, G),
+        ACDEFH(A, C, D, E, F, H),
+        ACDEGH(A, C, D, E, G, H),
+        ACDFGH(A, C, D, F, G, H),
+        ACEFGH(A, C, E, F, G, H),
+        ADEFGH(A, D, E, F, G, H),
+        BCDEFG(B, C, D, E, F, G),
+        BCDEFH(B, C, D, E, F, H),
+        BCDEGH(B, C, D, E, G, H),
+        BCDFGH(B, C, D, F, G, H),
+        BCEFGH(B, C, E, F, G, H),
+        BDEFGH(B, D, E, F, G, H),
+        CDEFGH(C, D, E, F, G, H),
+        ABCDEFG(A, B, C, D, E, F, G),
+        ABCDEFH(A, B, C, D, E, F, H),
+        ABCDEGH(A, B, C, D, E, G, H),
+        ABCDFGH(A, B, C, D, F, G, H),
+        ABCEFGH(A, B, C, E, F, G, H),
+        ABDEFGH(A, B, D, E, F, G, H),
+        ACDEFGH(A, C, D, E, F, G, H),
+        BCDEFGH(B, C, D, E, F, G, H),
+        ABCDEFGH(A, B, C, D, E, F, G, H),
+    }
+}